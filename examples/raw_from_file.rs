@@ -0,0 +1,5 @@
+link_args::windows::raw_from_file!(unsafe "args.linkargs");
+
+fn main() {
+    println!("Hello world!");
+}
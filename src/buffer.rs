@@ -0,0 +1,572 @@
+/// Helps to construct a list of argumets for the linker.
+/// For the MSVC linker, arguments are in the form:
+///
+/// `/DIRECTIVE:value`
+///
+/// Or:
+///
+/// `/DIRECTIVE:value1,value2`
+///
+/// Multiple arguments are separated by a space:
+///
+/// `/DIRECTIVE:value /DIRECTIVE:value1,value2`
+pub struct Buffer<const CAPACITY: usize> {
+    pub buffer: [u8; CAPACITY],
+    pub len: usize,
+}
+#[allow(unused)]
+impl<const CAPACITY: usize> Buffer<CAPACITY> {
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub const fn push_directive(self, argument: &str) -> Self {
+        self
+            .push( b"/")
+            .push(argument.as_bytes())
+    }
+    
+    pub const fn push_value(self, value: &str) -> Self {
+        if value.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        self
+            .push(b":")
+            .push(value.as_bytes())
+    }
+
+    /// Turns u32's into a string such as `0x800000`.
+    /// Then pushes them as values.
+    pub const fn push_values_hex(mut self, values: &[u32]) -> Self {
+        if values.is_empty() { return self; }
+        let mut index = 0;
+        self = self.push(b":");
+        while index < values.len() - 1 {
+            self = self.push_hex(values[index] as u64).push(b",");
+            index += 1;
+        }
+        self.push_hex(values[index] as u64)
+    }
+
+    pub const fn push_value_hex(self, value: u32) -> Self {
+        self
+            .push(b":")
+            .push_hex(value as u64)
+    }
+
+    /// Pushes a hex string, such as `0x800000`, without any leading zeros.
+    pub const fn push_hex(mut self, value: u64) -> Self {
+        let (digits, len) = to_hex_u64(value);
+        let mut index = 0;
+        while index < len {
+            self = self.push(&[digits[index]]);
+            index += 1;
+        }
+        self
+    }
+
+    pub const fn push_value_dec(self, value: u32) -> Self {
+        self
+            .push(b":")
+            .push_dec(value)
+    }
+
+    /// Pushes the decimal digits of a u32 without any leading zeros.
+    pub const fn push_dec(mut self, value: u32) -> Self {
+        let (digits, len) = to_dec_u32(value);
+        let mut index = 0;
+        while index < len {
+            self = self.push(&[digits[index]]);
+            index += 1;
+        }
+        self
+    }
+
+    pub const fn push_value_hex_u64(self, value: u64) -> Self {
+        self
+            .push(b":")
+            .push_hex(value)
+    }
+
+    pub const fn push_value_quoted(self, value: &str) -> Self {
+        self
+            .push(b":")
+            .push_quoted(value)
+    }
+
+    /// Pushes the value surrounded by quotes, without a leading `:`.
+    ///
+    /// There's no reliable way to escape a quote within a directive so values
+    /// containing quotes are rejected.
+    pub const fn push_quoted(self, value: &str) -> Self {
+        if value.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        if has_quote(value.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        self
+            .push(b"\"")
+            .push(value.as_bytes())
+            .push(b"\"")
+    }
+    
+    /// Pushes a symbol name, without a leading `:`.
+    ///
+    /// Symbols are never quoted so they can't contain spaces or quotes.
+    pub const fn push_symbol(self, symbol: &str) -> Self {
+        if symbol.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        if has_quote(symbol.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        if has_space(symbol.as_bytes()) {
+            panic!("link_args: symbols cannot contain a space");
+        }
+        self.push(symbol.as_bytes())
+    }
+
+    /// Pushes the value, only quoting it if it contains a space.
+    pub const fn push_value_maybe_quoted(self, value: &str) -> Self {
+        if has_quote(value.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        if has_space(value.as_bytes()) {
+            self.push_value_quoted(value)
+        } else {
+            self.push_value(value)
+        }
+    }
+    
+    /// Pushes `prefix` followed by `value` as a single argument, surrounding
+    /// the whole argument with quotes if the value contains a space. This is
+    /// how arguments are quoted in a response file for the GNU linker.
+    pub const fn push_arg_maybe_quoted(self, prefix: &str, value: &str) -> Self {
+        if value.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        if has_quote(value.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        if has_space(value.as_bytes()) {
+            self
+                .push(b"\"")
+                .push(prefix.as_bytes())
+                .push(value.as_bytes())
+                .push(b"\"")
+        } else {
+            self
+                .push(prefix.as_bytes())
+                .push(value.as_bytes())
+        }
+    }
+
+    /// Pushes the bytes that have been written to another buffer.
+    pub const fn push_buffer<const N: usize>(mut self, other: Buffer<N>) -> Self {
+        let mut index = 0;
+        while index < other.len {
+            self = self.push(&[other.buffer[index]]);
+            index += 1;
+        }
+        self
+    }
+    
+    pub const fn push_seperator(self) -> Self {
+        self.push(b" ")
+    }
+    
+    pub const fn push(mut self, src: &[u8]) -> Self {
+        if src.len() > CAPACITY - self.len {
+            capacity_exceeded(CAPACITY);
+        }
+        let offset = self.len;
+        while self.len - offset < src.len() {
+            self.buffer[self.len] = src[self.len - offset];
+            self.len += 1;
+        }
+        self
+    }
+
+    /// The same as `push` except that it returns `false`, leaving the buffer
+    /// unchanged, if `src` doesn't fit.
+    pub const fn try_push(self, src: &[u8]) -> (Self, bool) {
+        if src.len() > CAPACITY - self.len {
+            return (self, false);
+        }
+        (self.push(src), true)
+    }
+}
+
+/// Panics with a message that includes the buffer's capacity.
+///
+/// A `const` panic can't format numbers and a `&str` of a computed length
+/// can't be sliced from an array in a `const fn`, so the number is written into
+/// a fixed size message that is padded with trailing spaces.
+const fn capacity_exceeded(capacity: usize) -> ! {
+    const PREFIX: &[u8] = b"link_args: buffer capacity ";
+    const SUFFIX: &[u8] = b" exceeded by directive";
+    // The longest `usize` in decimal is 20 digits.
+    let mut message = [b' '; PREFIX.len() + 20 + SUFFIX.len()];
+    let mut len = 0;
+    while len < PREFIX.len() {
+        message[len] = PREFIX[len];
+        len += 1;
+    }
+    let mut digits = 1;
+    let mut val = capacity;
+    while val >= 10 {
+        val /= 10;
+        digits += 1;
+    }
+    let mut val = capacity;
+    let mut index = digits;
+    while index > 0 {
+        index -= 1;
+        message[len + index] = b'0' + (val % 10) as u8;
+        val /= 10;
+    }
+    len += digits;
+    let mut index = 0;
+    while index < SUFFIX.len() {
+        message[len + index] = SUFFIX[index];
+        index += 1;
+    }
+    // SAFETY: The message is only made up of ASCII.
+    panic!("{}", unsafe { core::str::from_utf8_unchecked(&message) })
+}
+
+pub const fn has_quote(s: &[u8]) -> bool {
+    let mut index = 0;
+    while index < s.len() {
+        if s[index] == b'"' { return true; }
+        index += 1;
+    }
+    false
+}
+
+/// Compares two strings.
+pub const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() { return false; }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Compares two strings, ignoring ASCII case.
+pub const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() { return false; }
+    let mut index = 0;
+    while index < a.len() {
+        if !a[index].eq_ignore_ascii_case(&b[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns `true` if `bytes[start..end]` is the directive `name`, ignoring
+/// ASCII case.
+pub const fn is_directive_at(bytes: &[u8], start: usize, end: usize, name: &str) -> bool {
+    let name = name.as_bytes();
+    if end - start != name.len() { return false; }
+    let mut index = 0;
+    while index < name.len() {
+        if !bytes[start + index].eq_ignore_ascii_case(&name[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns `true` if the string at `index` also appears earlier in `list`,
+/// ignoring ASCII case.
+pub const fn is_repeated(list: &[&str], index: usize) -> bool {
+    let mut earlier = 0;
+    while earlier < index {
+        if eq_ignore_ascii_case(list[earlier].as_bytes(), list[index].as_bytes()) {
+            return true;
+        }
+        earlier += 1;
+    }
+    false
+}
+
+/// Returns `true` if the bytes are valid UTF-8.
+///
+/// `core::str::from_utf8` is only a `const fn` since Rust 1.63 but the minimum
+/// supported version is 1.57.
+pub const fn is_utf8(s: &[u8]) -> bool {
+    let mut index = 0;
+    while index < s.len() {
+        let first = s[index];
+        let (width, min, max) = match first {
+            0x00..=0x7f => (1, 0x80, 0xbf),
+            0xc2..=0xdf => (2, 0x80, 0xbf),
+            // Reject overlong encodings.
+            0xe0 => (3, 0xa0, 0xbf),
+            // Reject surrogates.
+            0xed => (3, 0x80, 0x9f),
+            0xe1..=0xef => (3, 0x80, 0xbf),
+            // Reject overlong encodings.
+            0xf0 => (4, 0x90, 0xbf),
+            // Reject anything above U+10FFFF.
+            0xf4 => (4, 0x80, 0x8f),
+            0xf1..=0xf3 => (4, 0x80, 0xbf),
+            _ => return false,
+        };
+        if width > s.len() - index {
+            return false;
+        }
+        let mut next = 1;
+        while next < width {
+            let byte = s[index + next];
+            // Only the second byte has a restricted range.
+            let (min, max) = if next == 1 { (min, max) } else { (0x80, 0xbf) };
+            if byte < min || byte > max {
+                return false;
+            }
+            next += 1;
+        }
+        index += width;
+    }
+    true
+}
+
+pub const fn has_space(s: &[u8]) -> bool {
+    let mut index = 0;
+    while index < s.len() {
+        if s[index] == b' ' { return true; }
+        index += 1;
+    }
+    false
+}
+
+/// Turns a u64 into a hex string, such as `0x800000`, without leading zeros.
+/// Returns the digits and how many of them are used.
+pub const fn to_hex_u64(val: u64) -> ([u8; 18], usize) {
+    let mut val = val;
+    let mut bytes = *b"0x0000000000000000";
+    let lookup = [
+        b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8',
+        b'9', b'a', b'b', b'c', b'd', b'e', b'f',
+    ];
+    let len = hex_len(val);
+    let mut index = len - 1;
+    while index > 1 {
+        bytes[index] = lookup[(val & 0xf) as usize];
+        val >>= 4;
+        index -= 1;
+    }
+    (bytes, len)
+}
+
+/// The length of the hex string for a u64, including the `0x` prefix.
+pub const fn hex_len(val: u64) -> usize {
+    let mut val = val >> 4;
+    let mut digits = 1;
+    while val != 0 {
+        digits += 1;
+        val >>= 4;
+    }
+    "0x".len() + digits
+}
+
+/// Turns a u32 into a decimal string without leading zeros.
+/// Returns the digits and how many of them are used.
+pub const fn to_dec_u32(val: u32) -> ([u8; 10], usize) {
+    let mut val = val;
+    let mut reversed = [0; 10];
+    let mut len = 0;
+    loop {
+        reversed[len] = b'0' + (val % 10) as u8;
+        val /= 10;
+        len += 1;
+        if val == 0 { break; }
+    }
+    let mut bytes = [0; 10];
+    let mut index = 0;
+    while index < len {
+        bytes[index] = reversed[len - index - 1];
+        index += 1;
+    }
+    (bytes, len)
+}
+
+/// Implements the methods that are the same for each linker's `LinkArgs`.
+///
+/// The examples use the module at `$path`. `$method` adds an argument that
+/// is written as `$bytes`, which is `$size` bytes long.
+macro_rules! impl_link_args_common {
+    ($path:literal, $method:literal, $size:literal, $bytes:literal $(,)?) => {
+        impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
+            /// Create an empty argument list with the `CAPACITY` of the type.
+            ///
+            /// Adding more arguments than will fit in `CAPACITY` is an error.
+            /// When used in a `const` this fails to compile.
+            ///
+            #[doc = concat!("```compile_fail
+use ", $path, "::LinkArgs;
+
+const ARGS: [u8; 1] = LinkArgs::new()", $method, ".into_array();
+```")]
+            pub const fn new() -> Self {
+                Self {
+                    buffer: Buffer::new()
+                }
+            }
+            /// Get the length in bytes.
+            pub const fn len(&self) -> usize {
+                self.buffer.len
+            }
+            /// Returns `true` if no arguments have been added.
+            ///
+            /// # Examples
+            ///
+            #[doc = concat!("```rust
+use ", $path, "::LinkArgs;
+
+let args = LinkArgs::<64>::new();
+assert!(args.is_empty());
+assert!(!args", $method, ".is_empty());
+```")]
+            pub const fn is_empty(&self) -> bool {
+                self.buffer.len == 0
+            }
+            /// The maximum length in bytes.
+            ///
+            /// # Examples
+            ///
+            #[doc = concat!("```rust
+use ", $path, "::LinkArgs;
+
+let args = LinkArgs::<64>::new()", $method, ";
+assert_eq!(args.capacity(), 64);
+assert_eq!(args.len(), \"", $bytes, "\".len());
+```")]
+            pub const fn capacity(&self) -> usize {
+                CAPACITY
+            }
+            /// The bytes that have been written so far.
+            ///
+            /// This is not a `const fn` because slicing isn't possible in a
+            /// `const` on Rust 1.57, the minimum supported version.
+            ///
+            /// # Examples
+            ///
+            #[doc = concat!("```rust
+use ", $path, "::LinkArgs;
+
+let args = LinkArgs::<64>::new()", $method, ";
+assert_eq!(args.as_bytes(), b\"", $bytes, "\");
+```")]
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.buffer.buffer[..self.buffer.len]
+            }
+            /// The arguments that have been written so far, as a string.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the arguments are not valid UTF-8. This can only
+            /// happen if invalid UTF-8 was added as raw bytes.
+            ///
+            /// # Examples
+            ///
+            #[doc = concat!("```rust
+use ", $path, "::LinkArgs;
+
+let args = LinkArgs::<64>::new()", $method, ";
+assert_eq!(args.as_str(), \"", $bytes, "\");
+```")]
+            pub fn as_str(&self) -> &str {
+                core::str::from_utf8(self.as_bytes()).unwrap()
+            }
+            /// Consume the `LinkArgs` and return its byte buffer.
+            pub const fn into_array(self) -> [u8; CAPACITY] {
+                self.buffer.buffer
+            }
+            /// Consume the `LinkArgs` and return its byte buffer, checking that
+            /// every byte has been written.
+            ///
+            /// Unlike [`into_array`](Self::into_array) this panics if the length
+            /// is not exactly `CAPACITY`. Any unused capacity would otherwise be
+            /// left as NUL bytes.
+            ///
+            /// # Examples
+            ///
+            #[doc = concat!("```rust
+use ", $path, "::{ArgSize, LinkArgs};
+
+const ARGS: [u8; ", $size, "] = LinkArgs::new()", $method, ".into_exact_array();
+assert_eq!(&ARGS, b\"", $bytes, "\");
+```")]
+            ///
+            /// A buffer that's too large fails to compile.
+            ///
+            #[doc = concat!("```compile_fail
+use ", $path, "::{ArgSize, LinkArgs};
+
+const ARGS: [u8; ", $size, " + 1] = LinkArgs::new()", $method, ".into_exact_array();
+```")]
+            pub const fn into_exact_array(self) -> [u8; CAPACITY] {
+                if self.buffer.len != CAPACITY {
+                    panic!("link_args: the length of the arguments does not match the capacity");
+                }
+                self.buffer.buffer
+            }
+        }
+
+        /// The same as [`LinkArgs::new`].
+        ///
+        #[doc = concat!("```rust
+use ", $path, "::LinkArgs;
+
+let args: LinkArgs<64> = Default::default();
+assert!(args.is_empty());
+```")]
+        impl<const CAPACITY: usize> Default for LinkArgs<CAPACITY> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Buffer;
+
+    #[test]
+    fn try_push_fits() {
+        let (buffer, pushed) = Buffer::<8>::new().try_push(b"/DLL");
+        assert!(pushed);
+        assert_eq!(buffer.len, 4);
+        assert_eq!(&buffer.buffer[..buffer.len], b"/DLL");
+    }
+
+    #[test]
+    fn try_push_does_not_fit() {
+        let (buffer, pushed) = Buffer::<8>::new().push(b"/DLL").try_push(b"/NOLOGO");
+        assert!(!pushed);
+        assert_eq!(buffer.len, 4);
+        assert_eq!(&buffer.buffer, b"/DLL\0\0\0\0");
+    }
+
+    #[test]
+    fn try_push_fills_capacity() {
+        let (buffer, pushed) = Buffer::<8>::new().push(b"/DLL").try_push(b" /NO");
+        assert!(pushed);
+        assert_eq!(buffer.len, 8);
+        assert_eq!(&buffer.buffer, b"/DLL /NO");
+    }
+}
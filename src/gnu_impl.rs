@@ -0,0 +1,391 @@
+mod macros;
+
+use crate::buffer::{Buffer, has_quote, has_space};
+
+/// Functions to help to calculate the byte length of an argument.
+pub struct ArgSize;
+impl ArgSize {
+    /// The size of the `-s` argument.
+    pub const STRIP_ALL: usize = "-s ".len();
+    /// The size of an argument made from a `prefix` and a `value` that's
+    /// quoted if it contains a space.
+    const fn maybe_quoted(prefix: &str, value: &str) -> usize {
+        let quotes = if has_space(value.as_bytes()) { 2 } else { 0 };
+        prefix.len() + value.len() + quotes + " ".len()
+    }
+    /// The size of the `-l` argument.
+    pub const fn library(name: &str) -> usize {
+        Self::maybe_quoted("-l", name)
+    }
+    /// The size of the `-L` argument.
+    pub const fn library_path(path: &str) -> usize {
+        Self::maybe_quoted("-L", path)
+    }
+    /// The size of the `-rpath` argument.
+    pub const fn rpath(path: &str) -> usize {
+        Self::maybe_quoted("-rpath=", path)
+    }
+    /// The size of the `-rpath-link` argument.
+    pub const fn rpath_link(path: &str) -> usize {
+        Self::maybe_quoted("-rpath-link=", path)
+    }
+    /// The size of the `--gc-sections` or `--no-gc-sections` argument.
+    pub const fn gc_sections(enabled: bool) -> usize {
+        if enabled {
+            "--gc-sections ".len()
+        } else {
+            "--no-gc-sections ".len()
+        }
+    }
+    /// The size of the `--version-script` argument.
+    pub const fn version_script(path: &str) -> usize {
+        Self::maybe_quoted("--version-script=", path)
+    }
+    /// The size of the `--wrap` argument.
+    pub const fn wrap(symbol: &str) -> usize {
+        Self::maybe_quoted("--wrap=", symbol)
+    }
+    /// The size of the `--defsym` argument.
+    pub const fn defsym(name: &str, value: &str) -> usize {
+        let quoted = has_space(name.as_bytes()) || has_space(value.as_bytes());
+        let quotes = if quoted { 2 } else { 0 };
+        "--defsym== ".len() + name.len() + value.len() + quotes
+    }
+    /// The size of one or more raw arguments.
+    pub const fn raw(raw: &str) -> usize {
+        raw.len() + 1
+    }
+}
+
+/// An iterator over the arguments in a [`LinkArgs`].
+///
+/// This is created by [`LinkArgs::iter`] and
+/// [`macos::LinkArgs::iter`](crate::macos::LinkArgs::iter). Arguments that are
+/// quoted because they contain a space are returned without the quotes.
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::gnu::LinkArgs;
+///
+/// let args = unsafe { LinkArgs::<64>::new().raw("--gc-sections -s") };
+/// let args: Vec<&str> = args.iter().collect();
+/// assert_eq!(args, ["--gc-sections", "-s"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArgsIter<'a> {
+    rest: &'a str,
+}
+impl<'a> ArgsIter<'a> {
+    pub(crate) fn new(rest: &'a str) -> Self {
+        Self { rest }
+    }
+}
+impl<'a> Iterator for ArgsIter<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.rest.trim_start_matches(' ');
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let mut in_quotes = false;
+        let mut end = rest.len();
+        for (index, byte) in rest.bytes().enumerate() {
+            match byte {
+                b'"' => in_quotes = !in_quotes,
+                b' ' if !in_quotes => {
+                    end = index;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let (arg, rest) = rest.split_at(end);
+        self.rest = rest;
+        if arg.len() >= 2 && arg.starts_with('"') && arg.ends_with('"') {
+            Some(&arg[1..arg.len() - 1])
+        } else {
+            Some(arg)
+        }
+    }
+}
+
+/// Helps to construct arguments for the GNU linker, `ld`.
+///
+/// Arguments are written the same way as in a response file (`@file`). They
+/// are separated by a space and an argument containing a space is surrounded
+/// by double quotes.
+///
+/// See the [`gnu`](crate::gnu) module for how to pass these to the linker.
+pub struct LinkArgs<const CAPACITY: usize> {
+    buffer: Buffer<CAPACITY>,
+}
+impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
+    /// The `-l` argument. Links the library `name`.
+    ///
+    /// The linker searches the library path for `libname.so` or `libname.a`.
+    /// Names containing a space are quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::library("ssl")] = LinkArgs::new()
+    ///     .library("ssl")
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"-lssl ");
+    /// ```
+    pub const fn library(mut self, name: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("-l", name)
+            .push_seperator();
+        self
+    }
+    /// The `-L` argument. Adds `path` to the directories searched for
+    /// libraries.
+    ///
+    /// A path containing a space is quoted. The quotes surround the whole
+    /// argument, which is what `ld` expects in a response file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const SIZE: usize = ArgSize::library_path("/opt/my lib") + ArgSize::library_path("vendor");
+    /// const ARGS: LinkArgs<SIZE> = LinkArgs::new()
+    ///     .library_path("/opt/my lib")
+    ///     .library_path("vendor");
+    /// assert_eq!(ARGS.as_str(), "\"-L/opt/my lib\" -Lvendor ");
+    ///
+    /// let args: Vec<&str> = ARGS.iter().collect();
+    /// assert_eq!(args, ["-L/opt/my lib", "-Lvendor"]);
+    /// ```
+    pub const fn library_path(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("-L", path)
+            .push_seperator();
+        self
+    }
+    /// The `-rpath` argument. Adds `path` to the runtime library search path.
+    ///
+    /// The path is written as is, so `$ORIGIN` (the directory containing the
+    /// binary) is left for the dynamic loader to expand. A path containing a
+    /// space is quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::rpath("$ORIGIN/../lib")] = LinkArgs::new()
+    ///     .rpath("$ORIGIN/../lib")
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"-rpath=$ORIGIN/../lib ");
+    /// ```
+    pub const fn rpath(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("-rpath=", path)
+            .push_seperator();
+        self
+    }
+    /// The `-rpath-link` argument. Adds `path` to the directories searched
+    /// for the shared libraries needed by other shared libraries at link time.
+    ///
+    /// Unlike [`rpath`](Self::rpath) this doesn't affect the binary at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::rpath_link("/opt/sdk/lib")] = LinkArgs::new()
+    ///     .rpath_link("/opt/sdk/lib")
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"-rpath-link=/opt/sdk/lib ");
+    /// ```
+    pub const fn rpath_link(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("-rpath-link=", path)
+            .push_seperator();
+        self
+    }
+    /// The `--gc-sections` argument, or `--no-gc-sections` if `enabled` is
+    /// `false`. Removes sections that are never referenced.
+    ///
+    /// Be aware that a `#[used]` static only stops the compiler from removing
+    /// it. The linker can still remove it with `--gc-sections` unless it's
+    /// referenced or marked `#[used(linker)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::gc_sections(true)] = LinkArgs::new()
+    ///     .gc_sections(true)
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"--gc-sections ");
+    ///
+    /// const NO_GC: [u8; ArgSize::gc_sections(false)] = LinkArgs::new()
+    ///     .gc_sections(false)
+    ///     .into_exact_array();
+    /// assert_eq!(&NO_GC, b"--no-gc-sections ");
+    /// ```
+    pub const fn gc_sections(mut self, enabled: bool) -> Self {
+        let arg = if enabled { "--gc-sections" } else { "--no-gc-sections" };
+        self.buffer = self.buffer
+            .push(arg.as_bytes())
+            .push_seperator();
+        self
+    }
+    /// The `-s` argument. Removes all symbol information from the binary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::STRIP_ALL] = LinkArgs::new()
+    ///     .strip_all()
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"-s ");
+    /// ```
+    pub const fn strip_all(mut self) -> Self {
+        self.buffer = self.buffer
+            .push(b"-s")
+            .push_seperator();
+        self
+    }
+    /// The `--version-script` argument. Uses the version script at `path` to
+    /// control which symbols a shared library exports.
+    ///
+    /// The path is relative to the directory the linker is run in. A path
+    /// containing a space is quoted. See `examples/exports.map` in this crate's
+    /// repository for an example version script.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::version_script("examples/exports.map")] = LinkArgs::new()
+    ///     .version_script("examples/exports.map")
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"--version-script=examples/exports.map ");
+    /// ```
+    pub const fn version_script(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("--version-script=", path)
+            .push_seperator();
+        self
+    }
+    /// The `--wrap` argument. References to `symbol` use `__wrap_symbol`
+    /// instead and `__real_symbol` refers to the original `symbol`.
+    ///
+    /// Call this once for each symbol to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const SIZE: usize = ArgSize::wrap("malloc") + ArgSize::wrap("free");
+    /// const ARGS: [u8; SIZE] = LinkArgs::new()
+    ///     .wrap("malloc")
+    ///     .wrap("free")
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"--wrap=malloc --wrap=free ");
+    /// ```
+    pub const fn wrap(mut self, symbol: &str) -> Self {
+        self.buffer = self.buffer
+            .push_arg_maybe_quoted("--wrap=", symbol)
+            .push_seperator();
+        self
+    }
+    /// The `--defsym` argument. Defines the global symbol `name` as `value`,
+    /// which can be an address or a simple expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const SIZE: usize = ArgSize::defsym("BUILD_ID", "0x2a") + ArgSize::defsym("END", "start + 0x100");
+    /// const ARGS: LinkArgs<SIZE> = LinkArgs::new()
+    ///     .defsym("BUILD_ID", "0x2a")
+    ///     .defsym("END", "start + 0x100");
+    /// assert_eq!(ARGS.as_str(), "--defsym=BUILD_ID=0x2a \"--defsym=END=start + 0x100\" ");
+    /// ```
+    pub const fn defsym(mut self, name: &str, value: &str) -> Self {
+        if name.is_empty() || value.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        if has_quote(name.as_bytes()) || has_quote(value.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        let quoted = has_space(name.as_bytes()) || has_space(value.as_bytes());
+        let mut buffer = self.buffer;
+        if quoted {
+            buffer = buffer.push(b"\"");
+        }
+        buffer = buffer
+            .push(b"--defsym=")
+            .push(name.as_bytes())
+            .push(b"=")
+            .push(value.as_bytes());
+        if quoted {
+            buffer = buffer.push(b"\"");
+        }
+        self.buffer = buffer.push_seperator();
+        self
+    }
+    /// One or more raw arguments, separated by a space.
+    ///
+    /// # Safety
+    ///
+    /// The arguments are passed to the linker unchecked. It is up to the caller
+    /// to make sure they don't produce an unsound binary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::raw("--as-needed")] = unsafe {
+    ///     LinkArgs::new().raw("--as-needed").into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"--as-needed ");
+    /// ```
+    pub const unsafe fn raw(mut self, raw: &str) -> Self {
+        self.buffer = self.buffer.push(raw.as_bytes()).push_seperator();
+        self
+    }
+
+    /// An iterator over each argument.
+    ///
+    /// This can be used in a build script to pass the arguments to the linker.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::gnu::LinkArgs;
+    ///
+    /// let args = unsafe { LinkArgs::<64>::new().raw("--as-needed") };
+    /// for arg in args.iter() {
+    ///     println!("cargo:rustc-link-arg=-Wl,{}", arg);
+    /// }
+    /// ```
+    pub fn iter(&self) -> ArgsIter<'_> {
+        ArgsIter::new(self.as_str())
+    }
+}
+
+impl_link_args_common!(
+    "link_args::gnu",
+    ".strip_all()",
+    "ArgSize::STRIP_ALL",
+    "-s ",
+);
@@ -0,0 +1,44 @@
+/// Checks GNU linker arguments at compile time.
+///
+/// This builds the arguments in a `const`, which makes mistakes such as empty
+/// values a compile error. The arguments themselves are not emitted.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_gnu_check {
+    ($size:expr, $args:expr) => {
+        const _: () = {
+            const ARGS: $crate::gnu::LinkArgs<{ $size }> = $args;
+            let _ = ARGS.into_exact_array();
+        };
+    };
+}
+
+/// Links one or more libraries.
+///
+/// This uses the `#[link]` attribute, which `rustc` turns into a `-l` argument,
+/// so the names have to be string literals. Libraries are only linked for
+/// targets that use `ld` or a compatible linker. These are the Unix targets
+/// other than Apple's, such as `linux-gnu`, `linux-musl` and the BSDs, and
+/// `windows-gnu`.
+///
+/// See [`LinkArgs::library`](crate::gnu::LinkArgs::library).
+///
+/// # Example
+///
+/// ```rust
+/// link_args::gnu::library!("m");
+/// ```
+#[macro_export]
+macro_rules! gnu_library {
+    ($($lib:literal),+ $(,)?) => {
+        $crate::impl_gnu_check!(
+            0 $(+ $crate::gnu::ArgSize::library($lib))+,
+            $crate::gnu::LinkArgs::new()$(.library($lib))+
+        );
+        $(
+            #[cfg(any(all(unix, not(target_vendor = "apple")), all(windows, target_env = "gnu")))]
+            #[link(name = $lib)]
+            extern "C" {}
+        )+
+    };
+}
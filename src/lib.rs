@@ -1,7 +1,9 @@
 #![no_std]
 
-//! Allows setting linker arugments at compile time without a build script.
-//! Currently only supports Windows MSVC toolchains.
+//! Allows setting linker arguments at compile time without a build script.
+//! Currently only supports Windows MSVC toolchains. The [`gnu`] and [`macos`]
+//! modules can build arguments for those linkers but can't embed them, see
+//! their documentation for details.
 //!
 //! # Usage
 //!
@@ -24,6 +26,12 @@
 //! link_args::windows::stack_size!(0x800000);
 //! ```
 //!
+//! The [`kib!`], [`mib!`] and [`gib!`] macros can make sizes easier to read.
+//!
+//! ```rust
+//! link_args::windows::stack_size!(link_args::mib!(8));
+//! ```
+//!
 //! ## Add a default library
 //!
 //! Add "kernel32.lib" to the libraries that are serached for symbols.
@@ -43,6 +51,10 @@
 //! }
 //! ```
 //!
+//! Each argument can also be set on its own using the macro of the same name
+//! in the [`windows`](mod@windows) module, such as
+//! `link_args::windows::subsystem!(Windows);`.
+//!
 //! If you use unsafe linker arguments the you must mark the whole block as
 //! `unsafe`.
 //! 
@@ -65,27 +77,146 @@
 //! }
 //! ```
 //!
+//! ## Require an MSVC target
+//!
+//! Linker arguments are ignored when not targeting MSVC. Use
+//! [`windows_strict!`] instead of [`windows!`] to make this a compile error.
+//!
+//! # Features
+//!
+//! * `std`: Enables `windows::msvc::preview` and `windows_preview!` which
+//!   return the generated arguments as a `String`.
+//! * `dump`: Makes [`windows::dump!`] fail to compile with an error showing the
+//!   generated arguments.
+//! * `used_linker`: Mark the linker directives with `#[used(linker)]` so that
+//!   they can't be removed before the linker sees them. This requires a nightly
+//!   compiler and `#![feature(used_with_arg)]` in your crate.
+//!
 //! <style>#macros + table > tbody > tr:not(:first-child) { display: none !important; }</style>
 //!
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod buffer;
 mod msvc_impl;
+mod gnu_impl;
+mod macos_impl;
+mod size;
 
 
 
+/// The same as the [`windows`](mod@windows) module.
+///
+/// This is kept so that code using the `windows_msvc` paths still works.
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows_msvc::stack_size!(0x800000);
+/// link_args::windows_msvc::default_lib!("kernel32.lib");
+/// link_args::windows_msvc::raw!(unsafe "/ENTRY:mainCRTStartup");
+/// link_args::windows_msvc! {
+///     heap_size(0x100000);
+/// }
+/// ```
+pub mod windows_msvc {
+    pub use crate::windows::*;
+}
+
 /// Set linker arguments for the Windows toolchain
 pub mod windows {
     #[doc(inline)]
     pub use crate::windows_raw as raw;
-
     #[doc(inline)]
-    pub use crate::windows_msvc_stack_size as stack_size;
+    pub use crate::windows_raw_from_file as raw_from_file;
     #[doc(inline)]
-    pub use crate::windows_msvc_default_lib as default_lib;
+    pub use crate::windows_dump as dump;
+
+    // Defines the `windows::NAME!` macros, such as `windows::stack_size!`.
+    crate::impl_msvc_absolute_macros!($);
 
     /// Helpers for constructing MSVC linker arguments.
     pub mod msvc {
         // These are mostly exported so I can use links.
         pub use crate::msvc_impl::LinkArgs;
+        pub use crate::msvc_impl::ArgsIter;
+        pub use crate::msvc_impl::StaticDirective;
+        #[doc(hidden)]
+        pub use crate::msvc_impl::is_utf8;
+        #[cfg(feature = "std")]
+        pub use crate::msvc_impl::preview;
         pub use crate::msvc_impl::ArgSize;
+        pub use crate::msvc_impl::Subsystem;
+        pub use crate::msvc_impl::ExportOptions;
+        pub use crate::msvc_impl::SectionFlags;
+        pub use crate::msvc_impl::OptFlags;
+        pub use crate::msvc_impl::DebugMode;
+        pub use crate::msvc_impl::GuardFlags;
+        pub use crate::msvc_impl::Machine;
+        pub use crate::msvc_impl::DriverMode;
+        pub use crate::msvc_impl::SwapRun;
+        pub use crate::msvc_impl::ErrorReport;
+        pub use crate::msvc_impl::ForceMode;
+        pub use crate::msvc_impl::ManifestMode;
+        pub use crate::msvc_impl::Verbose;
+        pub use crate::msvc_impl::ClrImageType;
+        pub use crate::msvc_impl::DebugType;
     }
 }
+
+/// Set linker arguments for the GNU linker, `ld`.
+///
+/// # Limitations
+///
+/// Unlike the MSVC linker, `ld` has no way for an object file to pass arguments
+/// to the linker. A macro that only checked the arguments would silently do
+/// nothing so there are no such macros in this module. The exception is
+/// [`library!`](gnu::library) which uses `#[link]`.
+///
+/// To use the arguments, build them with [`LinkArgs`](gnu::LinkArgs) in a
+/// build script and pass each one to the linker through `rustc`:
+///
+/// ```rust
+/// use link_args::gnu::LinkArgs;
+///
+/// let args = unsafe { LinkArgs::<64>::new().raw("--as-needed") };
+/// for arg in args.iter() {
+///     println!("cargo:rustc-link-arg=-Wl,{}", arg);
+/// }
+/// ```
+pub mod gnu {
+    #[doc(inline)]
+    pub use crate::gnu_library as library;
+
+    pub use crate::gnu_impl::LinkArgs;
+    pub use crate::gnu_impl::ArgsIter;
+    pub use crate::gnu_impl::ArgSize;
+}
+
+/// Set linker arguments for the macOS linker, `ld64`.
+///
+/// # Limitations
+///
+/// `ld64` only accepts library and framework options from object files, and
+/// even those can't be set from Rust without a `#[link]` attribute. A macro
+/// that only checked the arguments would silently do nothing so there are no
+/// macros in this module.
+///
+/// To use the arguments, build them with [`LinkArgs`](macos::LinkArgs) in a
+/// build script and pass each one to the linker through `rustc`:
+///
+/// ```rust
+/// use link_args::macos::LinkArgs;
+///
+/// let args = LinkArgs::<32>::new().stack_size(0x800000);
+/// for arg in args.iter() {
+///     println!("cargo:rustc-link-arg=-Wl,{}", arg);
+/// }
+/// ```
+pub mod macos {
+    pub use crate::macos_impl::LinkArgs;
+    pub use crate::gnu_impl::ArgsIter;
+    pub use crate::macos_impl::ArgSize;
+}
@@ -0,0 +1,95 @@
+use crate::buffer::{Buffer, hex_len};
+use crate::gnu::ArgsIter;
+
+/// Functions to help to calculate the byte length of an argument.
+pub struct ArgSize;
+impl ArgSize {
+    /// The size of the `-stack_size` argument.
+    pub const fn stack_size(size: u64) -> usize {
+        "-stack_size  ".len() + hex_len(size)
+    }
+    /// The size of one or more raw arguments.
+    pub const fn raw(raw: &str) -> usize {
+        raw.len() + 1
+    }
+}
+
+/// Helps to construct arguments for the macOS linker, `ld64`.
+///
+/// Arguments are separated by a space. Some arguments, such as
+/// `-stack_size`, take their value as the next argument.
+///
+/// See the [`macos`](crate::macos) module for how to pass these to the linker.
+pub struct LinkArgs<const CAPACITY: usize> {
+    buffer: Buffer<CAPACITY>,
+}
+impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
+    /// The `-stack_size` argument. Sets the maximum size of the main thread's
+    /// stack in bytes.
+    ///
+    /// `ld64` requires the size to be a multiple of the page size. Be aware
+    /// that Rust's `std::thread` sets its own stack size for other threads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::macos::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::stack_size(0x800000)] = LinkArgs::new()
+    ///     .stack_size(0x800000)
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"-stack_size 0x800000 ");
+    /// ```
+    pub const fn stack_size(mut self, size: u64) -> Self {
+        self.buffer = self.buffer
+            .push(b"-stack_size ")
+            .push_hex(size)
+            .push_seperator();
+        self
+    }
+    /// One or more raw arguments, separated by a space.
+    ///
+    /// # Safety
+    ///
+    /// The arguments are passed to the linker unchecked. It is up to the caller
+    /// to make sure they don't produce an unsound binary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::macos::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::raw("-dead_strip")] = unsafe {
+    ///     LinkArgs::new().raw("-dead_strip").into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"-dead_strip ");
+    /// ```
+    pub const unsafe fn raw(mut self, raw: &str) -> Self {
+        self.buffer = self.buffer.push(raw.as_bytes()).push_seperator();
+        self
+    }
+
+    /// An iterator over each argument.
+    ///
+    /// This can be used in a build script to pass the arguments to the linker.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::macos::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().stack_size(0x800000);
+    /// let args: Vec<&str> = args.iter().collect();
+    /// assert_eq!(args, ["-stack_size", "0x800000"]);
+    /// ```
+    pub fn iter(&self) -> ArgsIter<'_> {
+        ArgsIter::new(self.as_str())
+    }
+}
+
+impl_link_args_common!(
+    "link_args::macos",
+    ".stack_size(0x800000)",
+    "ArgSize::stack_size(0x800000)",
+    "-stack_size 0x800000 ",
+);
@@ -1,101 +1,3178 @@
-mod buffer;
 mod macros;
 
-use buffer::Buffer;
+use crate::buffer::{Buffer, bytes_eq, has_quote, has_space, hex_len, is_directive_at, is_repeated, to_dec_u32};
+pub use crate::buffer::is_utf8;
+
+/// The library that implements delay loading.
+const DELAY_LOAD_HELPER: &str = "delayimp.lib";
+
+/// The environment an image will run in. Used by the `SUBSYSTEM` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subsystem {
+    /// A Windows character-mode application.
+    Console,
+    /// An application that doesn't require a console, such as a GUI application.
+    Windows,
+    /// Kernel mode drivers for Windows NT.
+    Native,
+    /// An Extensible Firmware Interface application.
+    EfiApplication,
+    /// An Extensible Firmware Interface boot service driver.
+    EfiBootServiceDriver,
+    /// An Extensible Firmware Interface ROM image.
+    EfiRom,
+    /// An Extensible Firmware Interface runtime driver.
+    EfiRuntimeDriver,
+    /// An application that runs with the POSIX subsystem.
+    Posix,
+    /// An application that runs in the Windows boot environment.
+    BootApplication,
+}
+impl Subsystem {
+    /// The name the linker uses for the subsystem.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Console => "CONSOLE",
+            Self::Windows => "WINDOWS",
+            Self::Native => "NATIVE",
+            Self::EfiApplication => "EFI_APPLICATION",
+            Self::EfiBootServiceDriver => "EFI_BOOT_SERVICE_DRIVER",
+            Self::EfiRom => "EFI_ROM",
+            Self::EfiRuntimeDriver => "EFI_RUNTIME_DRIVER",
+            Self::Posix => "POSIX",
+            Self::BootApplication => "BOOT_APPLICATION",
+        }
+    }
+}
+
+/// Options for the `EXPORT` directive.
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::windows::msvc::ExportOptions;
+///
+/// const OPTIONS: ExportOptions = ExportOptions {
+///     ordinal: Some(3),
+///     noname: true,
+///     ..ExportOptions::new()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// The index of the function in the export table.
+    pub ordinal: Option<u16>,
+    /// Only export the function by its ordinal. This is ignored if no
+    /// `ordinal` is set.
+    pub noname: bool,
+    /// Export a data item instead of a function.
+    pub data: bool,
+    /// Don't add the export to the import library.
+    pub private: bool,
+}
+impl ExportOptions {
+    /// Export by name with no other options.
+    pub const fn new() -> Self {
+        Self {
+            ordinal: None,
+            noname: false,
+            data: false,
+            private: false,
+        }
+    }
+}
+
+/// Section attributes for the `SECTION` directive.
+///
+/// Flags can be combined using [`union`](Self::union).
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::windows::msvc::SectionFlags;
+///
+/// const FLAGS: SectionFlags = SectionFlags::READ.union(SectionFlags::WRITE);
+/// assert!(FLAGS.contains(SectionFlags::WRITE));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SectionFlags(u32);
+impl SectionFlags {
+    /// The section can be discarded.
+    pub const DISCARD: Self = Self(1 << 0);
+    /// The section is executable.
+    pub const EXECUTE: Self = Self(1 << 1);
+    /// The section is not cachable.
+    pub const NOCACHE: Self = Self(1 << 2);
+    /// The section is not pageable.
+    pub const NOPAGE: Self = Self(1 << 3);
+    /// The section is readable.
+    pub const READ: Self = Self(1 << 4);
+    /// The section is shared among all processes that load the image.
+    pub const SHARED: Self = Self(1 << 5);
+    /// The section is writable.
+    pub const WRITE: Self = Self(1 << 6);
+
+    // The flags and their letters, in the order the linker documents them.
+    const LETTERS: [(Self, u8); 7] = [
+        (Self::EXECUTE, b'E'),
+        (Self::READ, b'R'),
+        (Self::WRITE, b'W'),
+        (Self::SHARED, b'S'),
+        (Self::DISCARD, b'D'),
+        (Self::NOCACHE, b'K'),
+        (Self::NOPAGE, b'P'),
+    ];
+
+    /// No flags set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// The raw bits of the flags.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    /// Combine the flags in `self` and `other`.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    /// Returns `true` if all the flags in `other` are also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// The number of attribute letters needed for these flags.
+    const fn letter_count(self) -> usize {
+        let mut count = 0;
+        let mut index = 0;
+        while index < Self::LETTERS.len() {
+            if self.contains(Self::LETTERS[index].0) {
+                count += 1;
+            }
+            index += 1;
+        }
+        count
+    }
+}
+
+/// Optimizations for the `OPT` directive.
+///
+/// Only the optimizations that are set will be passed to the linker.
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::windows::msvc::OptFlags;
+///
+/// const FLAGS: OptFlags = OptFlags::new().ref_().icf(3);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptFlags {
+    reference: Option<bool>,
+    icf: Option<bool>,
+    icf_iterations: u32,
+    lbr: Option<bool>,
+}
+impl OptFlags {
+    /// No optimizations set.
+    pub const fn new() -> Self {
+        Self {
+            reference: None,
+            icf: None,
+            icf_iterations: 0,
+            lbr: None,
+        }
+    }
+    /// `REF`. Removes functions and data that are never referenced.
+    pub const fn ref_(mut self) -> Self {
+        self.reference = Some(true);
+        self
+    }
+    /// `NOREF`. Keeps functions and data that are never referenced.
+    pub const fn no_ref(mut self) -> Self {
+        self.reference = Some(false);
+        self
+    }
+    /// `ICF=count`. Folds identical functions and data, using `count`
+    /// iterations to find duplicates.
+    pub const fn icf(mut self, count: u32) -> Self {
+        self.icf = Some(true);
+        self.icf_iterations = count;
+        self
+    }
+    /// `NOICF`. Disables identical folding.
+    pub const fn no_icf(mut self) -> Self {
+        self.icf = Some(false);
+        self
+    }
+    /// `LBR`. Enables ARM long branch optimizations.
+    pub const fn lbr(mut self) -> Self {
+        self.lbr = Some(true);
+        self
+    }
+    /// `NOLBR`. Disables ARM long branch optimizations.
+    pub const fn no_lbr(mut self) -> Self {
+        self.lbr = Some(false);
+        self
+    }
+    /// Returns `true` if no optimizations are set.
+    pub const fn is_empty(&self) -> bool {
+        self.reference.is_none() && self.icf.is_none() && self.lbr.is_none()
+    }
+}
+
+/// The kind of debug information to generate. Used by the `DEBUG` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugMode {
+    /// Let the linker decide what to generate.
+    Default,
+    /// Don't generate debug information.
+    None,
+    /// Put all debug information into the PDB.
+    Full,
+    /// Generate a limited PDB that points to the debug information in the
+    /// object and library files.
+    FastLink,
+}
+impl DebugMode {
+    const fn value(self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::None => Some("NONE"),
+            Self::Full => Some("FULL"),
+            Self::FastLink => Some("FASTLINK"),
+        }
+    }
+}
+
+/// Control flow protections for the `GUARD` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuardFlags {
+    /// Enable Control Flow Guard.
+    Cf,
+    /// Disable Control Flow Guard.
+    NoCf,
+    /// Enable EH continuation metadata.
+    EhCont,
+    /// Disable EH continuation metadata.
+    NoEhCont,
+}
+impl GuardFlags {
+    /// The value the linker uses for the flag.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cf => "CF",
+            Self::NoCf => "NO",
+            Self::EhCont => "EHCONT",
+            Self::NoEhCont => "EHCONT:NO",
+        }
+    }
+}
+
+/// The target platform of the image. Used by the `MACHINE` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Machine {
+    /// 32-bit x86.
+    X86,
+    /// 64-bit x86.
+    X64,
+    /// 32-bit ARM.
+    Arm,
+    /// 64-bit ARM.
+    Arm64,
+    /// 64-bit ARM with x64 emulation compatibility.
+    Arm64Ec,
+}
+impl Machine {
+    /// The name the linker uses for the machine.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::X86 => "X86",
+            Self::X64 => "X64",
+            Self::Arm => "ARM",
+            Self::Arm64 => "ARM64",
+            Self::Arm64Ec => "ARM64EC",
+        }
+    }
+    /// The machine for the `target_arch` being compiled for, if there is one.
+    pub const fn from_target() -> Option<Self> {
+        if cfg!(target_arch = "x86") {
+            Some(Self::X86)
+        } else if cfg!(target_arch = "x86_64") {
+            Some(Self::X64)
+        } else if cfg!(target_arch = "arm") {
+            Some(Self::Arm)
+        } else if cfg!(target_arch = "aarch64") {
+            Some(Self::Arm64)
+        } else if cfg!(target_arch = "arm64ec") {
+            Some(Self::Arm64Ec)
+        } else {
+            None
+        }
+    }
+}
+
+/// The kind of kernel mode driver to build. Used by the `DRIVER` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriverMode {
+    /// A kernel mode driver.
+    Default,
+    /// A driver that only runs on single processor machines.
+    UpOnly,
+    /// A Windows Driver Model driver.
+    Wdm,
+}
+impl DriverMode {
+    const fn value(self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::UpOnly => Some("UPONLY"),
+            Self::Wdm => Some("WDM"),
+        }
+    }
+}
+
+/// Where an image must be run from for it to be copied to the swap file first.
+/// Used by the `SWAPRUN` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapRun {
+    /// Removable media such as a CD.
+    Cd,
+    /// A network share.
+    Net,
+}
+impl SwapRun {
+    /// The value the linker uses for this kind of media.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cd => "CD",
+            Self::Net => "NET",
+        }
+    }
+}
+
+/// How internal linker errors are reported. Used by the `ERRORREPORT`
+/// directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorReport {
+    /// Never report errors.
+    None,
+    /// Ask before sending a report.
+    Prompt,
+    /// Queue the report to be sent later.
+    Queue,
+    /// Send the report automatically.
+    Send,
+}
+impl ErrorReport {
+    /// The value the linker uses for this option.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Prompt => "PROMPT",
+            Self::Queue => "QUEUE",
+            Self::Send => "SEND",
+        }
+    }
+}
+
+/// Which link errors to ignore. Used by the `FORCE` directive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForceMode {
+    /// Ignore symbols that are defined more than once.
+    Multiple,
+    /// Ignore symbols that are never defined.
+    Unresolved,
+}
+impl ForceMode {
+    /// The value the linker uses for this option.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Multiple => "MULTIPLE",
+            Self::Unresolved => "UNRESOLVED",
+        }
+    }
+}
+
+/// How the linker should create the side-by-side manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestMode {
+    /// Create a manifest file alongside the image.
+    Enabled,
+    /// Embed the manifest in the image as a resource.
+    Embed,
+    /// Do not create a manifest.
+    No,
+}
+impl ManifestMode {
+    const fn value(self) -> Option<&'static str> {
+        match self {
+            Self::Enabled => None,
+            Self::Embed => Some("EMBED"),
+            Self::No => Some("NO"),
+        }
+    }
+}
+
+/// What diagnostic information the linker should display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verbose {
+    /// Display all progress messages.
+    All,
+    /// Display which libraries are searched.
+    Lib,
+    /// Display information about unreferenced data or functions.
+    Ref,
+    /// Display information about identical COMDAT folding.
+    Icf,
+    /// Display libraries that were not used.
+    Unusedlibs,
+}
+impl Verbose {
+    const fn value(self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::Lib => Some("LIB"),
+            Self::Ref => Some("REF"),
+            Self::Icf => Some("ICF"),
+            Self::Unusedlibs => Some("UNUSEDLIBS"),
+        }
+    }
+}
+
+/// The kind of CLR image to create.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClrImageType {
+    /// A mixed image containing both native and managed code.
+    IjwOnly,
+    /// An image containing only managed code.
+    PureIl,
+    /// An image containing only verifiable managed code.
+    SafeIl,
+}
+impl ClrImageType {
+    /// The value the linker uses for this option.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::IjwOnly => "IJW",
+            Self::PureIl => "PURE",
+            Self::SafeIl => "SAFE",
+        }
+    }
+}
+
+/// The kinds of debug information for the `DEBUGTYPE` directive.
+///
+/// Flags can be combined using [`union`](Self::union).
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::windows::msvc::DebugType;
+///
+/// const TYPES: DebugType = DebugType::CV.union(DebugType::FIXUP);
+/// assert!(TYPES.contains(DebugType::FIXUP));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DebugType(u32);
+impl DebugType {
+    /// CodeView debug information.
+    pub const CV: Self = Self(1 << 0);
+    /// Exception handling data.
+    pub const PDATA: Self = Self(1 << 1);
+    /// Relocation tables, used by profiling tools.
+    pub const FIXUP: Self = Self(1 << 2);
+
+    // The flags and their names, in the order the linker documents them.
+    const NAMES: [(Self, &'static str); 3] = [
+        (Self::CV, "CV"),
+        (Self::PDATA, "PDATA"),
+        (Self::FIXUP, "FIXUP"),
+    ];
+
+    /// No flags set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// The raw bits of the flags.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    /// Combine the flags in `self` and `other`.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    /// Returns `true` if all the flags in `other` are also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// The length of the comma separated names for these flags.
+    const fn names_len(self) -> usize {
+        let mut len = 0;
+        let mut index = 0;
+        while index < Self::NAMES.len() {
+            let (flag, name) = Self::NAMES[index];
+            if self.contains(flag) {
+                if len != 0 {
+                    len += ",".len();
+                }
+                len += name.len();
+            }
+            index += 1;
+        }
+        len
+    }
+}
 
 /// Constants and functions to help to calculate the byte length of an argument.
 pub struct ArgSize;
 impl ArgSize {
-    /// The size of `STACK` directive with a `reserve` value.
-    pub const STACK_SIZE: usize = "/STACK:0x00000000 ".len();
-    /// The size of `STACK` directive with `reserve` and `commit` values.
-    pub const STACK_SIZE_WITH_COMMIT: usize = "/STACK:0x00000000,0x00000000 ".len();
     /// The size of the `NODEFAULTLIB` directive without any values.
     pub const DISABLE_ALL_DEFAULT_LIBS: usize = "/NODEFAULTLIB ".len();
+    /// The size of the `MAP` directive without a filename.
+    pub const MAP: usize = "/MAP ".len();
+    /// The size of the `WHOLEARCHIVE` directive.
+    pub const WHOLE_ARCHIVE: usize = "/WHOLEARCHIVE ".len();
+    /// The size of the `FUNCTIONPADMIN` directive.
+    pub const FUNCTION_PAD_MIN: usize = "/FUNCTIONPADMIN ".len();
+    /// The size of the `RELEASE` directive.
+    pub const RELEASE: usize = "/RELEASE ".len();
+    /// The size of the `PROFILE` directive.
+    pub const PROFILE: usize = "/PROFILE ".len();
+    /// The size of the `NOENTRY` directive.
+    pub const NO_ENTRY: usize = "/NOENTRY ".len();
+    /// The size of the `FORCE` directive without a value.
+    pub const FORCE_ALL: usize = "/FORCE ".len();
+    /// The size of the `INTEGRITYCHECK` directive.
+    pub const INTEGRITY_CHECK: usize = "/INTEGRITYCHECK ".len();
+    /// The size of the `KERNEL` directive.
+    pub const KERNEL: usize = "/KERNEL ".len();
+    /// The size of the `LTCG` directive.
+    pub const LTCG: usize = "/LTCG ".len();
+    /// The size of the `LTCG:INCREMENTAL` directive.
+    pub const LTCG_INCREMENTAL: usize = "/LTCG:INCREMENTAL ".len();
+    /// The size of the `LTCG:OFF` directive.
+    pub const LTCG_OFF: usize = "/LTCG:OFF ".len();
+    /// The size of the `VERBOSE` directive.
+    pub const VERBOSE_ALL: usize = "/VERBOSE ".len();
+    /// The size of the `NOLOGO` directive.
+    pub const NO_LOGO: usize = "/NOLOGO ".len();
+    /// The size of the `EMITPOGOPHASEINFO` directive.
+    pub const EMIT_POGO_PHASE_INFO: usize = "/EMITPOGOPHASEINFO ".len();
+    /// The size of the `DLL` directive.
+    pub const DLL: usize = "/DLL ".len();
+    /// The size of the `GENPROFILE` directive.
+    pub const GEN_PROFILE: usize = "/GENPROFILE ".len();
+    /// The size of the `USEPROFILE` directive.
+    pub const USE_PROFILE: usize = "/USEPROFILE ".len();
+    /// The size of `STACK` directive with a `reserve` value.
+    pub const fn stack_size(reserve: u32) -> usize {
+        "/STACK: ".len() + hex_len(reserve as u64)
+    }
+    /// The size of `STACK` directive with `reserve` and `commit` values.
+    pub const fn stack_size_with_commit(reserve: u32, commit: u32) -> usize {
+        "/STACK:, ".len() + hex_len(reserve as u64) + hex_len(commit as u64)
+    }
+    /// The size of `STACK` directive with a 64-bit `reserve` value.
+    pub const fn stack_size_u64(reserve: u64) -> usize {
+        "/STACK: ".len() + hex_len(reserve)
+    }
+    /// The size of `STACK` directive with 64-bit `reserve` and `commit` values.
+    pub const fn stack_size_with_commit_u64(reserve: u64, commit: u64) -> usize {
+        "/STACK:, ".len() + hex_len(reserve) + hex_len(commit)
+    }
+    /// The size of `HEAP` directive with a `reserve` value.
+    pub const fn heap_size(reserve: u32) -> usize {
+        "/HEAP: ".len() + hex_len(reserve as u64)
+    }
+    /// The size of `HEAP` directive with `reserve` and `commit` values.
+    pub const fn heap_size_with_commit(reserve: u32, commit: u32) -> usize {
+        "/HEAP:, ".len() + hex_len(reserve as u64) + hex_len(commit as u64)
+    }
+    /// The size of the `BASE` directive with an address.
+    pub const fn base(address: u64) -> usize {
+        "/BASE: ".len() + hex_len(address)
+    }
+    /// The size of the `DEPENDENTLOADFLAG` directive.
+    pub const fn dependent_load_flag(flags: u32) -> usize {
+        "/DEPENDENTLOADFLAG: ".len() + hex_len(flags as u64)
+    }
+    /// The largest possible size of the `STACK` directive with a `reserve`
+    /// value.
+    ///
+    /// A smaller value is written without leading zeros so this may be larger
+    /// than needed. Use [`LinkArgs::into_array`] rather than
+    /// [`LinkArgs::into_exact_array`] with this size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::STACK_SIZE] = LinkArgs::new()
+    ///     .stack_size(0x800000)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/STACK:0x800000 \0\0");
+    /// ```
+    #[deprecated(note = "use `ArgSize::stack_size`, which is the exact size")]
+    pub const STACK_SIZE: usize = "/STACK:0xFFFFFFFF ".len();
+    /// The largest possible size of the `STACK` directive with `reserve` and
+    /// `commit` values. See [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::stack_size_with_commit`, which is the exact size")]
+    pub const STACK_SIZE_WITH_COMMIT: usize = "/STACK:0xFFFFFFFF,0xFFFFFFFF ".len();
+    /// The largest possible size of the `STACK` directive with a 64-bit
+    /// `reserve` value. See [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::stack_size_u64`, which is the exact size")]
+    pub const STACK_SIZE_U64: usize = "/STACK:0xFFFFFFFFFFFFFFFF ".len();
+    /// The largest possible size of the `STACK` directive with 64-bit `reserve`
+    /// and `commit` values. See [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::stack_size_with_commit_u64`, which is the exact size")]
+    pub const STACK_SIZE_WITH_COMMIT_U64: usize =
+        "/STACK:0xFFFFFFFFFFFFFFFF,0xFFFFFFFFFFFFFFFF ".len();
+    /// The largest possible size of the `HEAP` directive with a `reserve`
+    /// value. See [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::heap_size`, which is the exact size")]
+    pub const HEAP_SIZE: usize = "/HEAP:0xFFFFFFFF ".len();
+    /// The largest possible size of the `HEAP` directive with `reserve` and
+    /// `commit` values. See [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::heap_size_with_commit`, which is the exact size")]
+    pub const HEAP_SIZE_WITH_COMMIT: usize = "/HEAP:0xFFFFFFFF,0xFFFFFFFF ".len();
+    /// The largest possible size of the `BASE` directive. See
+    /// [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::base`, which is the exact size")]
+    pub const BASE: usize = "/BASE:0xFFFFFFFFFFFFFFFF ".len();
+    /// The largest possible size of the `DEPENDENTLOADFLAG` directive. See
+    /// [`ArgSize::STACK_SIZE`].
+    #[deprecated(note = "use `ArgSize::dependent_load_flag`, which is the exact size")]
+    pub const DEPENDENT_LOAD_FLAG: usize = "/DEPENDENTLOADFLAG:0xFFFFFFFF ".len();
     /// The size of the `DEFAULTLIB` directive.
     pub const fn default_lib(lib: &str) -> usize {
         "/DEFAULTLIB: \"\"".len() + lib.len()
     }
-    /// The size of the `NODEFAULTLIB` directive with a value.
-    pub const fn no_default_lib(lib: &str) -> usize {
-        "/NODEFAULTLIB: \"\"".len() + lib.len()
+    /// The size of the `NODEFAULTLIB` directive with a value.
+    pub const fn no_default_lib(lib: &str) -> usize {
+        "/NODEFAULTLIB: \"\"".len() + lib.len()
+    }
+    /// The size of a `DEFAULTLIB` directive for each library.
+    pub const fn default_libs(libs: &[&str]) -> usize {
+        let mut size = 0;
+        let mut index = 0;
+        while index < libs.len() {
+            size += Self::default_lib(libs[index]);
+            index += 1;
+        }
+        size
+    }
+    /// The size of a `DEFAULTLIB` directive for each library, skipping any
+    /// repeated libraries.
+    pub const fn default_libs_dedup(libs: &[&str]) -> usize {
+        let mut size = 0;
+        let mut index = 0;
+        while index < libs.len() {
+            if !is_repeated(libs, index) {
+                size += Self::default_lib(libs[index]);
+            }
+            index += 1;
+        }
+        size
+    }
+    /// The size of a `NODEFAULTLIB` directive for each library.
+    pub const fn no_default_libs(libs: &[&str]) -> usize {
+        let mut size = 0;
+        let mut index = 0;
+        while index < libs.len() {
+            size += Self::no_default_lib(libs[index]);
+            index += 1;
+        }
+        size
+    }
+    /// The size of the `ENTRY` directive.
+    pub const fn entry(symbol: &str) -> usize {
+        let quotes = if has_space(symbol.as_bytes()) { "\"\"".len() } else { 0 };
+        "/ENTRY: ".len() + symbol.len() + quotes
+    }
+    /// The size of the `INCLUDE` directive.
+    pub const fn include(symbol: &str) -> usize {
+        "/INCLUDE: ".len() + symbol.len()
+    }
+    /// The size of the `EXPORT` directive.
+    pub const fn export(name: &str, options: ExportOptions) -> usize {
+        "/EXPORT: ".len() + name.len() + Self::export_options(options)
+    }
+    /// The size of the `EXPORT` directive when exporting under another name.
+    pub const fn export_as(external: &str, internal: &str, options: ExportOptions) -> usize {
+        "/EXPORT:= ".len() + external.len() + internal.len() + Self::export_options(options)
+    }
+    const fn export_options(options: ExportOptions) -> usize {
+        let mut size = 0;
+        if let Some(ordinal) = options.ordinal {
+            size += ",@".len() + to_dec_u32(ordinal as u32).1;
+            if options.noname {
+                size += ",NONAME".len();
+            }
+        }
+        if options.data {
+            size += ",DATA".len();
+        }
+        if options.private {
+            size += ",PRIVATE".len();
+        }
+        size
+    }
+    /// The size of the `MERGE` directive.
+    pub const fn merge(from: &str, to: &str) -> usize {
+        "/MERGE:= ".len() + from.len() + to.len()
+    }
+    /// The size of the `SECTION` directive.
+    pub const fn section(name: &str, flags: SectionFlags) -> usize {
+        "/SECTION:, ".len() + name.len() + flags.letter_count()
+    }
+    /// The size of the `BASE` directive using a file.
+    pub const fn base_with_file(filename: &str, key: &str) -> usize {
+        "/BASE:@\"\", ".len() + filename.len() + key.len()
+    }
+    /// The size of the `ALIGN` directive.
+    pub const fn align(bytes: u32) -> usize {
+        "/ALIGN: ".len() + to_dec_u32(bytes).1
+    }
+    /// The size of the `SUBSYSTEM` directive.
+    pub const fn subsystem(subsystem: Subsystem) -> usize {
+        "/SUBSYSTEM: ".len() + subsystem.as_str().len()
+    }
+    /// The size of the `SUBSYSTEM` directive with a version.
+    pub const fn subsystem_with_version(subsystem: Subsystem, major: u16, minor: u16) -> usize {
+        // The minor version is always at least two digits.
+        let minor_len = to_dec_u32(minor as u32).1;
+        let minor_len = if minor_len < 2 { 2 } else { minor_len };
+        "/SUBSYSTEM:,. ".len()
+            + subsystem.as_str().len()
+            + to_dec_u32(major as u32).1
+            + minor_len
+    }
+    /// The size of the `VERSION` directive.
+    pub const fn version(major: u16, minor: u16) -> usize {
+        "/VERSION:. ".len() + to_dec_u32(major as u32).1 + to_dec_u32(minor as u32).1
+    }
+    /// The size of the `OPT` directive. This is zero if no optimizations are
+    /// set.
+    pub const fn opt(flags: OptFlags) -> usize {
+        if flags.is_empty() {
+            return 0;
+        }
+        let mut size = "/OPT: ".len();
+        let mut count = 0;
+        if let Some(reference) = flags.reference {
+            size += if reference { "REF".len() } else { "NOREF".len() };
+            count += 1;
+        }
+        if let Some(icf) = flags.icf {
+            size += if icf {
+                "ICF=".len() + to_dec_u32(flags.icf_iterations).1
+            } else {
+                "NOICF".len()
+            };
+            count += 1;
+        }
+        if let Some(lbr) = flags.lbr {
+            size += if lbr { "LBR".len() } else { "NOLBR".len() };
+            count += 1;
+        }
+        // The commas between each optimization.
+        size + count - 1
+    }
+    /// The size of the `DEBUG` directive.
+    pub const fn debug(mode: DebugMode) -> usize {
+        match mode.value() {
+            Some(value) => "/DEBUG: ".len() + value.len(),
+            None => "/DEBUG ".len(),
+        }
+    }
+    /// The size of the `MAP` directive with a filename.
+    pub const fn map_to(filename: &str) -> usize {
+        "/MAP: \"\"".len() + filename.len()
+    }
+    /// The size of the `GUARD` directive.
+    pub const fn guard(flags: GuardFlags) -> usize {
+        "/GUARD: ".len() + flags.as_str().len()
+    }
+    /// The size of the `DYNAMICBASE` directive.
+    pub const fn dynamic_base(enabled: bool) -> usize {
+        Self::toggle("DYNAMICBASE", enabled)
+    }
+    /// The size of a directive that is either on or `:NO`.
+    const fn toggle(directive: &str, enabled: bool) -> usize {
+        let no = if enabled { 0 } else { ":NO".len() };
+        "/ ".len() + directive.len() + no
+    }
+    /// The size of the `NXCOMPAT` directive.
+    pub const fn nx_compat(enabled: bool) -> usize {
+        Self::toggle("NXCOMPAT", enabled)
+    }
+    /// The size of the `LARGEADDRESSAWARE` directive.
+    pub const fn large_address_aware(enabled: bool) -> usize {
+        Self::toggle("LARGEADDRESSAWARE", enabled)
+    }
+    /// The size of the `TSAWARE` directive.
+    pub const fn ts_aware(enabled: bool) -> usize {
+        Self::toggle("TSAWARE", enabled)
+    }
+    /// The size of the `DELAYLOAD` directive.
+    pub const fn delay_load(dll: &str) -> usize {
+        "/DELAYLOAD: \"\"".len() + dll.len()
+    }
+    /// The size of a `DELAYLOAD` directive for each DLL followed by one
+    /// `DEFAULTLIB` directive for the delay load helper.
+    pub const fn delay_load_with_helper(dlls: &[&str]) -> usize {
+        let mut size = Self::default_lib(DELAY_LOAD_HELPER);
+        let mut index = 0;
+        while index < dlls.len() {
+            size += Self::delay_load(dlls[index]);
+            index += 1;
+        }
+        size
+    }
+    /// The size of the `WHOLEARCHIVE` directive with a library.
+    pub const fn whole_archive_lib(lib: &str) -> usize {
+        "/WHOLEARCHIVE: \"\"".len() + lib.len()
+    }
+    /// The size of the `FUNCTIONPADMIN` directive with a size.
+    pub const fn function_pad_min_bytes(bytes: u32) -> usize {
+        "/FUNCTIONPADMIN: ".len() + to_dec_u32(bytes).1
+    }
+    /// The size of the `CETCOMPAT` directive.
+    pub const fn cet_compat(enabled: bool) -> usize {
+        Self::toggle("CETCOMPAT", enabled)
+    }
+    /// The size of the `SAFESEH` directive. This is zero if the target
+    /// architecture isn't x86.
+    pub const fn safe_seh(enabled: bool) -> usize {
+        if cfg!(target_arch = "x86") {
+            Self::toggle("SAFESEH", enabled)
+        } else {
+            0
+        }
+    }
+    /// The size of the `MACHINE` directive.
+    pub const fn machine(machine: Machine) -> usize {
+        "/MACHINE: ".len() + machine.as_str().len()
+    }
+    /// The size of the `MACHINE` directive for the current target. This is zero
+    /// if the target has no matching [`Machine`].
+    pub const fn machine_from_target() -> usize {
+        match Machine::from_target() {
+            Some(machine) => Self::machine(machine),
+            None => 0,
+        }
+    }
+    /// The size of the `NATVIS` directive.
+    pub const fn natvis(path: &str) -> usize {
+        "/NATVIS: \"\"".len() + path.len()
+    }
+    /// The size of the `IMPLIB` directive.
+    pub const fn impl_lib(path: &str) -> usize {
+        "/IMPLIB: \"\"".len() + path.len()
+    }
+    /// The size of the `PDB` directive.
+    pub const fn pdb(path: &str) -> usize {
+        "/PDB: \"\"".len() + path.len()
+    }
+    /// The size of the `PDBALTPATH` directive.
+    pub const fn pdb_alt_path(path: &str) -> usize {
+        let quotes = if has_space(path.as_bytes()) { "\"\"".len() } else { 0 };
+        "/PDBALTPATH: ".len() + path.len() + quotes
+    }
+    /// The size of the `STUB` directive.
+    pub const fn stub(path: &str) -> usize {
+        "/STUB: \"\"".len() + path.len()
+    }
+    /// The size of the `DRIVER` directive.
+    pub const fn driver(mode: DriverMode) -> usize {
+        match mode.value() {
+            Some(value) => "/DRIVER: ".len() + value.len(),
+            None => "/DRIVER ".len(),
+        }
+    }
+    /// The size of the `SWAPRUN` directive.
+    pub const fn swap_run(swap_run: SwapRun) -> usize {
+        "/SWAPRUN: ".len() + swap_run.as_str().len()
+    }
+    /// The size of the `SWAPRUN` directive with multiple values. This is zero
+    /// if `swap_runs` is empty.
+    pub const fn swap_run_all(swap_runs: &[SwapRun]) -> usize {
+        if swap_runs.is_empty() {
+            return 0;
+        }
+        let mut size = "/SWAPRUN: ".len() + swap_runs.len() - 1;
+        let mut index = 0;
+        while index < swap_runs.len() {
+            size += swap_runs[index].as_str().len();
+            index += 1;
+        }
+        size
+    }
+    /// The size of the `FIXED` directive.
+    pub const fn fixed(enabled: bool) -> usize {
+        Self::toggle("FIXED", enabled)
+    }
+    /// The size of the `ERRORREPORT` directive.
+    pub const fn error_report(error_report: ErrorReport) -> usize {
+        "/ERRORREPORT: ".len() + error_report.as_str().len()
+    }
+    /// The size of the `FORCE` directive with a value.
+    pub const fn force(force_mode: ForceMode) -> usize {
+        "/FORCE: ".len() + force_mode.as_str().len()
+    }
+    /// The size of the `IGNORE` directive.
+    pub const fn ignore_warning(number: u32) -> usize {
+        "/IGNORE: ".len() + to_dec_u32(number).1
+    }
+    /// The size of the `ORDER` directive.
+    pub const fn order(response_file: &str) -> usize {
+        "/ORDER:@\"\" ".len() + response_file.len()
+    }
+    /// The size of the `HIGHENTROPYVA` directive.
+    pub const fn high_entropy_va(enabled: bool) -> usize {
+        Self::toggle("HIGHENTROPYVA", enabled)
+    }
+    /// The size of the `APPCONTAINER` directive.
+    pub const fn app_container(enabled: bool) -> usize {
+        Self::toggle("APPCONTAINER", enabled)
+    }
+    /// The size of the `MANIFESTUAC` directive.
+    pub const fn manifest_uac(level: &str, ui_access: bool) -> usize {
+        let ui_access = if ui_access { "true".len() } else { "false".len() };
+        "/MANIFESTUAC:\"level='' uiAccess=''\" ".len() + level.len() + ui_access
+    }
+    /// The size of the `MANIFEST` directive.
+    pub const fn manifest(manifest_mode: ManifestMode) -> usize {
+        match manifest_mode.value() {
+            Some(value) => "/MANIFEST: ".len() + value.len(),
+            None => "/MANIFEST ".len(),
+        }
+    }
+    /// The size of the `MANIFESTINPUT` directive.
+    pub const fn manifest_input(path: &str) -> usize {
+        "/MANIFESTINPUT: \"\"".len() + path.len()
+    }
+    /// The size of both the `MANIFEST:EMBED` and `MANIFESTINPUT` directives.
+    pub const fn embed_manifest(path: &str) -> usize {
+        Self::manifest(ManifestMode::Embed) + Self::manifest_input(path)
+    }
+    /// The size of the `VERBOSE` directive.
+    pub const fn verbose(verbose: Verbose) -> usize {
+        match verbose.value() {
+            Some(value) => "/VERBOSE: ".len() + value.len(),
+            None => "/VERBOSE ".len(),
+        }
+    }
+    /// The size of the `INCREMENTAL` directive.
+    pub const fn incremental(enabled: bool) -> usize {
+        Self::toggle("INCREMENTAL", enabled)
+    }
+    /// The size of the `FILEALIGN` directive.
+    pub const fn file_align(bytes: u32) -> usize {
+        "/FILEALIGN: ".len() + to_dec_u32(bytes).1
+    }
+    /// The size of the `WX` directive.
+    pub const fn warnings_as_errors(enabled: bool) -> usize {
+        Self::toggle("WX", enabled)
+    }
+    /// The size of the `LINKREPRO` directive.
+    pub const fn link_repro(dir: &str) -> usize {
+        "/LINKREPRO: \"\"".len() + dir.len()
+    }
+    /// The size of the `LIBPATH` directive.
+    pub const fn lib_path(path: &str) -> usize {
+        "/LIBPATH: \"\"".len() + path.len()
+    }
+    /// The size of the `KEYFILE` directive.
+    pub const fn key_file(path: &str) -> usize {
+        "/KEYFILE: \"\"".len() + path.len()
+    }
+    /// The size of the `KEYCONTAINER` directive.
+    pub const fn key_container(name: &str) -> usize {
+        let quotes = if has_space(name.as_bytes()) { "\"\"".len() } else { 0 };
+        "/KEYCONTAINER: ".len() + name.len() + quotes
+    }
+    /// The size of the `DELAYSIGN` directive.
+    pub const fn delay_sign(enabled: bool) -> usize {
+        Self::toggle("DELAYSIGN", enabled)
+    }
+    /// The size of the `TLBOUT` directive.
+    pub const fn tlb_out(path: &str) -> usize {
+        "/TLBOUT: \"\"".len() + path.len()
+    }
+    /// The size of the `TLBID` directive.
+    pub const fn tlb_id(id: u16) -> usize {
+        "/TLBID: ".len() + to_dec_u32(id as u32).1
+    }
+    /// The size of the `WINMD` directive.
+    pub const fn winmd(enabled: bool) -> usize {
+        Self::toggle("WINMD", enabled)
+    }
+    /// The size of the `WINMDFILE` directive.
+    pub const fn winmd_file(path: &str) -> usize {
+        "/WINMDFILE: \"\"".len() + path.len()
+    }
+    /// The size of the `SOURCELINK` directive.
+    pub const fn source_link(path: &str) -> usize {
+        "/SOURCELINK: \"\"".len() + path.len()
+    }
+    /// The size of the `PDBSTRIPPED` directive.
+    pub const fn pdb_stripped(path: &str) -> usize {
+        "/PDBSTRIPPED: \"\"".len() + path.len()
+    }
+    /// The size of the `GENPROFILE` directive with a `PGD` file.
+    pub const fn gen_profile_to(pgd: &str) -> usize {
+        "/GENPROFILE:PGD=\"\" ".len() + pgd.len()
+    }
+    /// The size of the `USEPROFILE` directive with a `PGD` file.
+    pub const fn use_profile_from(pgd: &str) -> usize {
+        "/USEPROFILE:PGD=\"\" ".len() + pgd.len()
+    }
+    /// The size of the `CLRIMAGETYPE` directive.
+    pub const fn clr_image_type(clr_image_type: ClrImageType) -> usize {
+        "/CLRIMAGETYPE: ".len() + clr_image_type.as_str().len()
+    }
+    /// The size of the `CLRUNMANAGEDCODECHECK` directive.
+    pub const fn clr_unmanaged_code_check(enabled: bool) -> usize {
+        Self::toggle("CLRUNMANAGEDCODECHECK", enabled)
+    }
+    /// The size of the `DEBUGTYPE` directive.
+    pub const fn debug_type(types: DebugType) -> usize {
+        "/DEBUGTYPE: ".len() + types.names_len()
+    }
+}
+
+/// Returns the arguments as a `String`.
+///
+/// This is only for displaying the arguments while debugging. The linker may
+/// ignore or reject some arguments and it won't be reflected here.
+///
+/// # Examples
+///
+/// ```rust
+/// use link_args::windows::msvc::{preview, LinkArgs};
+///
+/// let args = LinkArgs::<64>::new().stack_size(0x800000);
+/// assert_eq!(preview(&args), "/STACK:0x800000 ");
+/// ```
+#[cfg(feature = "std")]
+pub fn preview<const N: usize>(args: &LinkArgs<N>) -> std::string::String {
+    std::string::String::from(args.as_str())
+}
+
+/// An iterator over the arguments in a [`LinkArgs`].
+///
+/// This is created by [`LinkArgs::iter`].
+///
+/// # Examples
+///
+/// Spaces inside quotes don't split an argument.
+///
+/// ```rust
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// let args = LinkArgs::<128>::new()
+///     .manifest_uac("asInvoker", false)
+///     .lib_path("C:\\Program Files\\lib")
+///     .no_logo();
+/// let args: Vec<&str> = args.iter().collect();
+/// assert_eq!(args, [
+///     "/MANIFESTUAC:\"level='asInvoker' uiAccess='false'\"",
+///     "/LIBPATH:\"C:\\Program Files\\lib\"",
+///     "/NOLOGO",
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArgsIter<'a> {
+    rest: &'a str,
+}
+impl<'a> Iterator for ArgsIter<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.rest.trim_start_matches(' ');
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let mut in_quotes = false;
+        let mut end = rest.len();
+        for (index, byte) in rest.bytes().enumerate() {
+            match byte {
+                b'"' => in_quotes = !in_quotes,
+                b' ' if !in_quotes => {
+                    end = index;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let (arg, rest) = rest.split_at(end);
+        self.rest = rest;
+        Some(arg)
+    }
+}
+
+/// The static created by a named [`windows!`](crate::windows!) block.
+///
+/// This lets you check which arguments were actually written to the object
+/// file, e.g. in your own tests. The static only exists when targeting MSVC so
+/// any code using it needs the same `cfg`.
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows! {
+///     static LINK_ARGS;
+///     stack_size(0x800000);
+/// }
+///
+/// #[cfg(all(windows, target_env = "msvc"))]
+/// assert_eq!(LINK_ARGS.bytes(), b"/STACK:0x800000 ");
+/// ```
+#[repr(transparent)]
+pub struct StaticDirective<const CAPACITY: usize> {
+    bytes: [u8; CAPACITY],
+}
+impl<const CAPACITY: usize> StaticDirective<CAPACITY> {
+    /// Wraps the bytes that will be put in the `.drectve` section.
+    pub const fn new(bytes: [u8; CAPACITY]) -> Self {
+        Self { bytes }
+    }
+    /// The linker arguments as they are written to the object file.
+    pub const fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Helps to construct MSVC linker arguments.
+pub struct LinkArgs<const CAPACITY: usize> {
+    buffer: Buffer::<CAPACITY>
+}
+impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
+    /// The `STACK` directive.
+    ///
+    /// `reserve` is the number of bytes of virtual memory to reserve for the
+    /// stack.
+    ///
+    /// # Examples
+    ///
+    /// The value is written in hex without any leading zeros.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::stack_size(0x800000)] = LinkArgs::new()
+    ///     .stack_size(0x800000)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/STACK:0x800000 ");
+    /// ```
+    pub const fn stack_size(mut self, reserve: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("STACK")
+            .push_value_hex(reserve)
+            .push_seperator();
+        self
+    }
+    /// The `STACK` directive with explicit commit value.
+    ///
+    /// `reserve` is the number of bytes of virtual memory to reserve for the
+    /// stack. `commit` is the number of byte of physical memory to allocate for
+    /// the stack when the program starts.
+    pub const fn stack_size_with_commit(mut self, reserve: u32, commit: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("STACK")
+            .push_values_hex(&[reserve, commit])
+            .push_seperator();
+        self
+    }
+    /// The `STACK` directive with a 64-bit `reserve` value.
+    ///
+    /// This is the same as [`stack_size`](Self::stack_size) except it allows
+    /// reserving more than 4 GiB on 64-bit targets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     stack_size_u64(0x1_0000_0000);
+    /// }
+    /// ```
+    pub const fn stack_size_u64(mut self, reserve: u64) -> Self {
+        self.buffer = self.buffer
+            .push_directive("STACK")
+            .push_value_hex_u64(reserve)
+            .push_seperator();
+        self
+    }
+    /// The `STACK` directive with explicit 64-bit reserve and commit values.
+    ///
+    /// This is the same as [`stack_size_with_commit`](Self::stack_size_with_commit)
+    /// except it allows values larger than 4 GiB on 64-bit targets.
+    pub const fn stack_size_with_commit_u64(mut self, reserve: u64, commit: u64) -> Self {
+        self.buffer = self.buffer
+            .push_directive("STACK")
+            .push_value_hex_u64(reserve)
+            .push(b",")
+            .push_hex(commit)
+            .push_seperator();
+        self
+    }
+    /// The `HEAP` directive.
+    ///
+    /// `reserve` is the number of bytes of virtual memory to reserve for the
+    /// heap.
+    pub const fn heap_size(mut self, reserve: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("HEAP")
+            .push_value_hex(reserve)
+            .push_seperator();
+        self
+    }
+    /// The `HEAP` directive with explicit commit value.
+    ///
+    /// `reserve` is the number of bytes of virtual memory to reserve for the
+    /// heap. `commit` is the number of byte of physical memory to allocate for
+    /// the heap when the program starts.
+    pub const fn heap_size_with_commit(mut self, reserve: u32, commit: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("HEAP")
+            .push_values_hex(&[reserve, commit])
+            .push_seperator();
+        self
+    }
+    /// The `SUBSYSTEM` directive. Sets the environment the image runs in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     subsystem(Windows);
+    /// }
+    /// ```
+    pub const fn subsystem(mut self, subsystem: Subsystem) -> Self {
+        self.buffer = self.buffer
+            .push_directive("SUBSYSTEM")
+            .push_value(subsystem.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `SUBSYSTEM` directive with the minimum required version of the
+    /// subsystem.
+    ///
+    /// The version is written as `major.minor` with the minor version padded to
+    /// two digits, e.g. `6.01`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs, Subsystem};
+    ///
+    /// const SIZE: usize = ArgSize::subsystem_with_version(Subsystem::Console, 6, 1);
+    /// const ARGS: [u8; SIZE] = LinkArgs::new()
+    ///     .subsystem_with_version(Subsystem::Console, 6, 1)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/SUBSYSTEM:CONSOLE,6.01 ");
+    /// ```
+    pub const fn subsystem_with_version(mut self, subsystem: Subsystem, major: u16, minor: u16) -> Self {
+        self.buffer = self.buffer
+            .push_directive("SUBSYSTEM")
+            .push_value(subsystem.as_str())
+            .push(b",")
+            .push_dec(major as u32)
+            .push(b".");
+        if minor < 10 {
+            self.buffer = self.buffer.push(b"0");
+        }
+        self.buffer = self.buffer
+            .push_dec(minor as u32)
+            .push_seperator();
+        self
+    }
+    /// The `ENTRY` directive. Sets the function that will be called when the
+    /// image is loaded.
+    ///
+    /// The symbol is only quoted if it contains a space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows::entry!("mainCRTStartup");
+    /// ```
+    pub const fn entry(mut self, symbol: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("ENTRY")
+            .push_value_maybe_quoted(symbol)
+            .push_seperator();
+        self
+    }
+    /// The `INCLUDE` directive. Forces the linker to include the symbol even if
+    /// nothing references it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol is empty or contains a space or a quote. When used
+    /// in a `const` this fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows::include!("my_static", "my_other_static");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::include("my static")] = LinkArgs::new()
+    ///     .include("my static")
+    ///     .into_array();
+    /// ```
+    pub const fn include(mut self, symbol: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("INCLUDE")
+            .push(b":")
+            .push_symbol(symbol)
+            .push_seperator();
+        self
+    }
+    /// The `EXPORT` directive. Exports a function or data item from the image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, ExportOptions, LinkArgs};
+    ///
+    /// const OPTIONS: ExportOptions = ExportOptions {
+    ///     ordinal: Some(3),
+    ///     noname: true,
+    ///     ..ExportOptions::new()
+    /// };
+    /// const ARGS: [u8; ArgSize::export("foo", OPTIONS)] = LinkArgs::new()
+    ///     .export("foo", OPTIONS)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/EXPORT:foo,@3,NONAME ");
+    /// ```
+    pub const fn export(mut self, name: &str, options: ExportOptions) -> Self {
+        self.buffer = self.buffer
+            .push_directive("EXPORT")
+            .push_value(name);
+        self.push_export_options(options)
+    }
+    /// The `EXPORT` directive. Exports the `internal` function or data item
+    /// using the `external` name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either name is empty or contains a space or a quote. When
+    /// used in a `const` this fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, ExportOptions, LinkArgs};
+    ///
+    /// const OPTIONS: ExportOptions = ExportOptions { data: true, ..ExportOptions::new() };
+    /// const ARGS: [u8; ArgSize::export_as("bar", "baz", OPTIONS)] = LinkArgs::new()
+    ///     .export_as("bar", "baz", OPTIONS)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/EXPORT:bar=baz,DATA ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, ExportOptions, LinkArgs};
+    ///
+    /// const OPTIONS: ExportOptions = ExportOptions::new();
+    /// const ARGS: [u8; ArgSize::export_as("bar", "", OPTIONS)] = LinkArgs::new()
+    ///     .export_as("bar", "", OPTIONS)
+    ///     .into_array();
+    /// ```
+    pub const fn export_as(mut self, external: &str, internal: &str, options: ExportOptions) -> Self {
+        self.buffer = self.buffer
+            .push_directive("EXPORT")
+            .push(b":")
+            .push_symbol(external)
+            .push(b"=")
+            .push_symbol(internal);
+        self.push_export_options(options)
+    }
+    const fn push_export_options(mut self, options: ExportOptions) -> Self {
+        if let Some(ordinal) = options.ordinal {
+            self.buffer = self.buffer.push(b",@").push_dec(ordinal as u32);
+            if options.noname {
+                self.buffer = self.buffer.push(b",NONAME");
+            }
+        }
+        if options.data {
+            self.buffer = self.buffer.push(b",DATA");
+        }
+        if options.private {
+            self.buffer = self.buffer.push(b",PRIVATE");
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `ALIGN` directive. Sets the alignment of each section in the image.
+    ///
+    /// `bytes` must be a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     align(0x1000);
+    /// }
+    /// ```
+    ///
+    /// Using an alignment that isn't a power of two fails to compile.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::align(3)] = LinkArgs::new().align(3).into_array();
+    /// ```
+    pub const fn align(mut self, bytes: u32) -> Self {
+        if !bytes.is_power_of_two() {
+            panic!("link_args: the ALIGN value must be a power of two");
+        }
+        self.buffer = self.buffer
+            .push_directive("ALIGN")
+            .push_value_dec(bytes)
+            .push_seperator();
+        self
+    }
+    /// The `DEFAULTLIB` directive. Adds a library to use.
+    /// 
+    /// Libraries specified on the command line will override default libraries if
+    /// there is a conflict.
+    ///
+    /// Library names cannot be empty.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::default_lib("")] = LinkArgs::new().default_lib("").into_array();
+    /// ```
+    ///
+    /// Nor can they contain a quote (`"`).
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const LIB: &str = "lib\"name";
+    /// const ARGS: [u8; ArgSize::default_lib(LIB)] = LinkArgs::new().default_lib(LIB).into_array();
+    /// ```
+    pub const fn default_lib(mut self, lib: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("DEFAULTLIB")
+            .push_value_quoted(lib)
+            .push_seperator();
+        self
+    }
+    /// The `NODEFAULTLIB` directive. Prevents a default library from being used.
+    ///
+    /// Overrides `default_lib`. Libraries specified on the command line will
+    /// bypass `no_default_lib`.
+    ///
+    /// This can be unsafe if used with `default_lib` to replace symbols.
+    ///
+    /// # Safety
+    ///
+    /// Removing a default library may cause symbols to be resolved from a
+    /// different library than the one the code was written for.
+    /// 
+    /// # Examples
+    ///
+    /// Prevent kernel32 from being linked.
+    ///
+    /// ```rust
+    /// link_args::windows::no_default_lib!(unsafe "kernel32.lib");
+    /// ```
+    pub const unsafe fn no_default_lib(mut self, lib: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("NODEFAULTLIB")
+            .push_value_quoted(lib)
+            .push_seperator();
+        self
+    }
+    /// A `DEFAULTLIB` directive for each library in `libs`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const LIBS: [&str; 2] = ["kernel32.lib", "advapi32.lib"];
+    /// const ARGS: [u8; ArgSize::default_libs(&LIBS)] = LinkArgs::new()
+    ///     .default_libs(&LIBS)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/DEFAULTLIB:\"kernel32.lib\" /DEFAULTLIB:\"advapi32.lib\" ");
+    /// ```
+    pub const fn default_libs(mut self, libs: &[&str]) -> Self {
+        let mut index = 0;
+        while index < libs.len() {
+            self = self.default_lib(libs[index]);
+            index += 1;
+        }
+        self
+    }
+    /// A `DEFAULTLIB` directive for each library in `libs`, skipping any
+    /// library that appears earlier in the list.
+    ///
+    /// Library names are compared ignoring ASCII case, the same as the linker.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const LIBS: [&str; 4] = ["kernel32.lib", "user32.lib", "KERNEL32.LIB", "user32.lib"];
+    /// const ARGS: [u8; ArgSize::default_libs_dedup(&LIBS)] = LinkArgs::new()
+    ///     .default_libs_dedup(&LIBS)
+    ///     .into_exact_array();
+    /// assert_eq!(&ARGS, b"/DEFAULTLIB:\"kernel32.lib\" /DEFAULTLIB:\"user32.lib\" ");
+    /// ```
+    pub const fn default_libs_dedup(mut self, libs: &[&str]) -> Self {
+        let mut index = 0;
+        while index < libs.len() {
+            if !is_repeated(libs, index) {
+                self = self.default_lib(libs[index]);
+            }
+            index += 1;
+        }
+        self
+    }
+    /// A `NODEFAULTLIB` directive for each library in `libs`.
+    ///
+    /// # Safety
+    ///
+    /// See [`no_default_lib`](Self::no_default_lib).
+    pub const unsafe fn no_default_libs(mut self, libs: &[&str]) -> Self {
+        let mut index = 0;
+        while index < libs.len() {
+            self = self.no_default_lib(libs[index]);
+            index += 1;
+        }
+        self
+    }
+    /// The `MERGE` directive. Combines the `from` section into the `to`
+    /// section.
+    ///
+    /// # Safety
+    ///
+    /// Merging sections with different attributes can corrupt the image. For
+    /// example, merging writable data into a read-only section.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     unsafe {
+    ///         merge(".rdata", ".text");
+    ///     }
+    /// }
+    /// ```
+    pub const unsafe fn merge(mut self, from: &str, to: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("MERGE")
+            .push_value(from)
+            .push(b"=")
+            .push(to.as_bytes())
+            .push_seperator();
+        self
+    }
+    /// The `SECTION` directive. Sets the attributes of a section.
+    ///
+    /// The attribute letters are written in the order the linker documents
+    /// them: `E`, `R`, `W`, `S`, `D`, `K` then `P`.
+    ///
+    /// # Safety
+    ///
+    /// Changing section attributes can make code or data accessible in ways
+    /// the compiler did not expect. For example, making read-only data
+    /// writable or shared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no flags are set. When used in a `const` this fails to
+    /// compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs, SectionFlags};
+    ///
+    /// const FLAGS: SectionFlags = SectionFlags::SHARED
+    ///     .union(SectionFlags::WRITE)
+    ///     .union(SectionFlags::READ)
+    ///     .union(SectionFlags::EXECUTE);
+    /// const ARGS: [u8; ArgSize::section(".mysec", FLAGS)] = unsafe {
+    ///     LinkArgs::new().section(".mysec", FLAGS).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/SECTION:.mysec,ERWS ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs, SectionFlags};
+    ///
+    /// const FLAGS: SectionFlags = SectionFlags::empty();
+    /// const ARGS: [u8; ArgSize::section(".mysec", FLAGS)] = unsafe {
+    ///     LinkArgs::new().section(".mysec", FLAGS).into_array()
+    /// };
+    /// ```
+    pub const unsafe fn section(mut self, name: &str, flags: SectionFlags) -> Self {
+        if flags.bits() == 0 {
+            panic!("link_args: the `SECTION` directive needs at least one flag");
+        }
+        self.buffer = self.buffer
+            .push_directive("SECTION")
+            .push_value(name)
+            .push(b",");
+        let mut index = 0;
+        while index < SectionFlags::LETTERS.len() {
+            let (flag, letter) = SectionFlags::LETTERS[index];
+            if flags.contains(flag) {
+                self.buffer = self.buffer.push(&[letter]);
+            }
+            index += 1;
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `BASE` directive. Sets the preferred base address of the image.
+    ///
+    /// # Safety
+    ///
+    /// An unsuitable base address can make the image unloadable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows::base!(unsafe 0x140000000);
+    /// ```
+    pub const unsafe fn base(mut self, address: u64) -> Self {
+        self.buffer = self.buffer
+            .push_directive("BASE")
+            .push_value_hex_u64(address)
+            .push_seperator();
+        self
+    }
+    /// The `BASE` directive using a file. The base address is looked up in
+    /// `filename` using the given `key`.
+    ///
+    /// Each line of the file has the form `key address size`.
+    ///
+    /// # Safety
+    ///
+    /// An unsuitable base address can make the image unloadable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `key` is empty or contains a quote or a space. When used
+    /// in a `const` this fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::base_with_file("bases.txt", "mydll")] = unsafe {
+    ///     LinkArgs::new().base_with_file("bases.txt", "mydll").into_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/BASE:@\"bases.txt\",mydll ");
+    /// ```
+    ///
+    /// The key isn't quoted so a space would start a new directive.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const KEY: &str = "mydll /ENTRY:evil";
+    /// const ARGS: [u8; ArgSize::base_with_file("bases.txt", KEY)] = unsafe {
+    ///     LinkArgs::new().base_with_file("bases.txt", KEY).into_array()
+    /// };
+    /// ```
+    pub const unsafe fn base_with_file(mut self, filename: &str, key: &str) -> Self {
+        if key.is_empty() {
+            panic!("link_args: directive values cannot be empty");
+        }
+        if has_quote(key.as_bytes()) {
+            panic!("link_args: values cannot contain a quote (\")");
+        }
+        if has_space(key.as_bytes()) {
+            panic!("link_args: the `BASE` key cannot contain a space");
+        }
+        self.buffer = self.buffer
+            .push_directive("BASE")
+            .push(b":@")
+            .push_quoted(filename)
+            .push(b",")
+            .push(key.as_bytes())
+            .push_seperator();
+        self
+    }
+    /// The `NODEFAULTLIB` directive wihout arguments. Prevent any default lib
+    /// from being used.
+    ///
+    /// Completely disables any and all use of `default_lib`. This is safe
+    /// because if a symbol is unavaliable the program will fail to compile.
+    pub const fn disable_all_default_libs(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("NODEFAULTLIB").push_seperator();
+        self
+    }
+    /// The `VERSION` directive. Stamps a version number into the image header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::version(1, 2)] = LinkArgs::new().version(1, 2).into_array();
+    /// assert_eq!(&ARGS, b"/VERSION:1.2 ");
+    /// ```
+    pub const fn version(mut self, major: u16, minor: u16) -> Self {
+        self.buffer = self.buffer
+            .push_directive("VERSION")
+            .push_value_dec(major as u32)
+            .push(b".")
+            .push_dec(minor as u32)
+            .push_seperator();
+        self
+    }
+    /// The `OPT` directive. Controls the optimizations done by the linker.
+    ///
+    /// All the optimizations are combined into a single directive. Nothing is
+    /// pushed if no optimizations are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs, OptFlags};
+    ///
+    /// const FLAGS: OptFlags = OptFlags::new().ref_().icf(3);
+    /// const ARGS: [u8; ArgSize::opt(FLAGS)] = LinkArgs::new().opt(FLAGS).into_array();
+    /// assert_eq!(&ARGS, b"/OPT:REF,ICF=3 ");
+    /// ```
+    pub const fn opt(mut self, flags: OptFlags) -> Self {
+        if flags.is_empty() {
+            return self;
+        }
+        self.buffer = self.buffer.push_directive("OPT").push(b":");
+        let mut first = true;
+        if let Some(reference) = flags.reference {
+            self.buffer = self.buffer.push(if reference { b"REF" } else { b"NOREF" });
+            first = false;
+        }
+        if let Some(icf) = flags.icf {
+            if !first {
+                self.buffer = self.buffer.push(b",");
+            }
+            if icf {
+                self.buffer = self.buffer.push(b"ICF=").push_dec(flags.icf_iterations);
+            } else {
+                self.buffer = self.buffer.push(b"NOICF");
+            }
+            first = false;
+        }
+        if let Some(lbr) = flags.lbr {
+            if !first {
+                self.buffer = self.buffer.push(b",");
+            }
+            self.buffer = self.buffer.push(if lbr { b"LBR" } else { b"NOLBR" });
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `DEBUG` directive. Controls the generation of debug information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     debug(FastLink);
+    /// }
+    /// ```
+    pub const fn debug(mut self, mode: DebugMode) -> Self {
+        self.buffer = self.buffer.push_directive("DEBUG");
+        if let Some(value) = mode.value() {
+            self.buffer = self.buffer.push_value(value);
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `MAP` directive. Writes a map file using the default name.
+    pub const fn map(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("MAP").push_seperator();
+        self
+    }
+    /// The `MAP` directive with a filename. Writes a map file to `filename`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::map_to("my app.map")] = LinkArgs::new()
+    ///     .map_to("my app.map")
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/MAP:\"my app.map\" ");
+    /// ```
+    pub const fn map_to(mut self, filename: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("MAP")
+            .push_value_quoted(filename)
+            .push_seperator();
+        self
+    }
+    /// The `GUARD` directive. Sets control flow protections.
+    ///
+    /// Use multiple `GUARD` directives to set more than one flag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     guard(Cf, EhCont);
+    /// }
+    /// ```
+    pub const fn guard(mut self, flags: GuardFlags) -> Self {
+        self.buffer = self.buffer
+            .push_directive("GUARD")
+            .push_value(flags.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `DYNAMICBASE` directive. Controls whether the image can be randomly
+    /// rebased at load time (ASLR).
+    ///
+    /// `dynamic_base(true)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// Disabling ASLR removes a security mitigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     dynamic_base(true);
+    /// }
+    /// link_args::windows! {
+    ///     unsafe {
+    ///         dynamic_base(false);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::dynamic_base(true) + ArgSize::dynamic_base(false)] = unsafe {
+    ///     LinkArgs::new().dynamic_base(true).dynamic_base(false).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/DYNAMICBASE /DYNAMICBASE:NO ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().dynamic_base(true);
+    /// ```
+    pub const unsafe fn dynamic_base(self, enabled: bool) -> Self {
+        self.toggle("DYNAMICBASE", enabled)
+    }
+    /// Push a directive that is either on or `:NO`.
+    const fn toggle(mut self, directive: &str, enabled: bool) -> Self {
+        self.buffer = self.buffer.push_directive(directive);
+        if !enabled {
+            self.buffer = self.buffer.push_value("NO");
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `NXCOMPAT` directive. Marks the image as compatible with Data
+    /// Execution Prevention (DEP).
+    ///
+    /// `nx_compat(true)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// Disabling DEP removes a security mitigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     nx_compat(true);
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::nx_compat(true) + ArgSize::nx_compat(false)] = unsafe {
+    ///     LinkArgs::new().nx_compat(true).nx_compat(false).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/NXCOMPAT /NXCOMPAT:NO ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().nx_compat(true);
+    /// ```
+    pub const unsafe fn nx_compat(self, enabled: bool) -> Self {
+        self.toggle("NXCOMPAT", enabled)
+    }
+    /// The `LARGEADDRESSAWARE` directive. Allows a 32-bit image to use more
+    /// than 2 GiB of address space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     large_address_aware(true);
+    /// }
+    /// ```
+    pub const fn large_address_aware(self, enabled: bool) -> Self {
+        self.toggle("LARGEADDRESSAWARE", enabled)
+    }
+    /// The `TSAWARE` directive. Marks the application as aware of
+    /// Terminal Server (Remote Desktop).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     ts_aware(true);
+    /// }
+    /// ```
+    pub const fn ts_aware(self, enabled: bool) -> Self {
+        self.toggle("TSAWARE", enabled)
+    }
+    /// The `DELAYLOAD` directive. Delays loading the DLL until one of its
+    /// functions is called.
+    ///
+    /// Delay loading requires the helper in `delayimp.lib`. Use
+    /// [`delay_load_with_helper`](Self::delay_load_with_helper) to also add it
+    /// as a default library.
+    pub const fn delay_load(mut self, dll: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("DELAYLOAD")
+            .push_value_quoted(dll)
+            .push_seperator();
+        self
+    }
+    /// A `DELAYLOAD` directive for each DLL in `dlls`, plus a single
+    /// `DEFAULTLIB` directive for `delayimp.lib` which provides the delay load
+    /// helper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const DLLS: [&str; 2] = ["user32.dll", "shell32.dll"];
+    /// const ARGS: [u8; ArgSize::delay_load_with_helper(&DLLS)] = LinkArgs::new()
+    ///     .delay_load_with_helper(&DLLS)
+    ///     .into_array();
+    /// assert_eq!(
+    ///     &ARGS,
+    ///     b"/DELAYLOAD:\"user32.dll\" /DELAYLOAD:\"shell32.dll\" /DEFAULTLIB:\"delayimp.lib\" "
+    /// );
+    /// ```
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     delay_load_with_helper("user32.dll", "shell32.dll");
+    /// }
+    /// ```
+    pub const fn delay_load_with_helper(mut self, dlls: &[&str]) -> Self {
+        let mut index = 0;
+        while index < dlls.len() {
+            self = self.delay_load(dlls[index]);
+            index += 1;
+        }
+        self.default_lib(DELAY_LOAD_HELPER)
+    }
+    /// The `WHOLEARCHIVE` directive. Includes every object file from
+    /// all libraries.
+    ///
+    /// # Safety
+    ///
+    /// Pulling in whole archives can change which definition of a symbol
+    /// is used.
+    pub const unsafe fn whole_archive(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("WHOLEARCHIVE").push_seperator();
+        self
+    }
+    /// The `WHOLEARCHIVE` directive with a library. Includes every object file
+    /// from the library.
+    ///
+    /// # Safety
+    ///
+    /// Pulling in whole archives can change which definition of a symbol
+    /// is used.
+    pub const unsafe fn whole_archive_lib(mut self, lib: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("WHOLEARCHIVE")
+            .push_value_quoted(lib)
+            .push_seperator();
+        self
+    }
+    /// The `FUNCTIONPADMIN` directive. Adds the default amount of padding
+    /// before each function so the image can be hotpatched.
+    pub const fn function_pad_min(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("FUNCTIONPADMIN").push_seperator();
+        self
+    }
+    /// The `FUNCTIONPADMIN` directive with a size. Adds `bytes` of padding
+    /// before each function so the image can be hotpatched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     function_pad_min_bytes(5);
+    /// }
+    /// ```
+    pub const fn function_pad_min_bytes(mut self, bytes: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("FUNCTIONPADMIN")
+            .push_value_dec(bytes)
+            .push_seperator();
+        self
+    }
+    /// The `CETCOMPAT` directive. Marks the image as compatible with
+    /// Control-flow Enforcement Technology (CET) shadow stacks.
+    ///
+    /// `cet_compat(true)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// Disabling CET removes a security mitigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     cet_compat(true);
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::cet_compat(true) + ArgSize::cet_compat(false)] = unsafe {
+    ///     LinkArgs::new().cet_compat(true).cet_compat(false).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/CETCOMPAT /CETCOMPAT:NO ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().cet_compat(true);
+    /// ```
+    pub const unsafe fn cet_compat(self, enabled: bool) -> Self {
+        self.toggle("CETCOMPAT", enabled)
+    }
+    /// The `DEPENDENTLOADFLAG` directive. Sets the `LoadLibraryEx` flags used
+    /// when loading the image's dependencies.
+    ///
+    /// # Safety
+    ///
+    /// The wrong flags can prevent the image's dependencies from loading or
+    /// cause them to be loaded from unexpected locations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // LOAD_LIBRARY_SEARCH_SYSTEM32
+    /// link_args::windows::dependent_load_flag!(unsafe 0x800);
+    /// ```
+    pub const unsafe fn dependent_load_flag(mut self, flags: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("DEPENDENTLOADFLAG")
+            .push_value_hex(flags)
+            .push_seperator();
+        self
+    }
+    /// The `RELEASE` directive. Sets the checksum in the image
+    /// header.
+    ///
+    /// Some drivers and services are required to have a checksum.
+    pub const fn release(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("RELEASE").push_seperator();
+        self
+    }
+    /// The `PROFILE` directive. Produces an image that can be used
+    /// with the Performance Tools profiler.
+    ///
+    /// This implies `/OPT:REF` and disables identical COMDAT folding.
+    pub const fn profile(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("PROFILE").push_seperator();
+        self
+    }
+    /// The `SAFESEH` directive. Produces a table of the image's safe exception
+    /// handlers.
+    ///
+    /// This only applies to x86 targets. Nothing is pushed for any other
+    /// architecture.
+    ///
+    /// `safe_seh(true)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// Disabling safe exception handlers removes a security mitigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     safe_seh(true);
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::safe_seh(true) + ArgSize::safe_seh(false)] = unsafe {
+    ///     LinkArgs::new().safe_seh(true).safe_seh(false).into_exact_array()
+    /// };
+    /// if cfg!(target_arch = "x86") {
+    ///     assert_eq!(&ARGS[..], b"/SAFESEH /SAFESEH:NO ");
+    /// } else {
+    ///     assert!(ARGS.is_empty());
+    /// }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().safe_seh(true);
+    /// ```
+    pub const unsafe fn safe_seh(self, enabled: bool) -> Self {
+        if cfg!(target_arch = "x86") {
+            self.toggle("SAFESEH", enabled)
+        } else {
+            self
+        }
+    }
+    /// The `MACHINE` directive. Sets the target platform of the image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     machine(X64);
+    /// }
+    /// ```
+    pub const fn machine(mut self, machine: Machine) -> Self {
+        self.buffer = self.buffer
+            .push_directive("MACHINE")
+            .push_value(machine.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `MACHINE` directive using the `target_arch` being compiled for.
+    ///
+    /// Nothing is pushed if the target has no matching [`Machine`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows::machine_from_target!();
+    /// ```
+    pub const fn machine_from_target(self) -> Self {
+        match Machine::from_target() {
+            Some(machine) => self.machine(machine),
+            None => self,
+        }
+    }
+    /// The `NATVIS` directive. Embeds a debugger visualization
+    /// file in the PDB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     natvis("my_types.natvis");
+    /// }
+    /// ```
+    pub const fn natvis(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("NATVIS")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `IMPLIB` directive. Sets the name of the import library
+    /// generated for a DLL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     impl_lib("my_lib.lib");
+    /// }
+    /// ```
+    pub const fn impl_lib(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("IMPLIB")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `PDB` directive. Sets the path of the generated PDB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     pdb("my_app.pdb");
+    /// }
+    /// ```
+    pub const fn pdb(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("PDB")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `PDBALTPATH` directive. Sets the PDB path that is recorded in the
+    /// image.
+    ///
+    /// The path is only quoted if it contains a space so linker variables such
+    /// as `%_PDB%` are passed through. This is useful for deterministic builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::pdb_alt_path("%_PDB%")] = LinkArgs::new()
+    ///     .pdb_alt_path("%_PDB%")
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/PDBALTPATH:%_PDB% ");
+    /// ```
+    pub const fn pdb_alt_path(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("PDBALTPATH")
+            .push_value_maybe_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `STUB` directive. Replaces the default MS-DOS stub
+    /// program at the start of the image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     stub("dos_stub.exe");
+    /// }
+    /// ```
+    pub const fn stub(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("STUB")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `DRIVER` directive. Builds a kernel mode driver.
+    ///
+    /// This is usually paired with
+    /// [`subsystem(Subsystem::Native)`](Self::subsystem).
+    ///
+    /// # Safety
+    ///
+    /// This fundamentally changes the type of image produced. Code that expects
+    /// to run in user mode will not work as a driver.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     unsafe {
+    ///         subsystem(Native);
+    ///         driver(Wdm);
+    ///     }
+    /// }
+    /// ```
+    pub const unsafe fn driver(mut self, mode: DriverMode) -> Self {
+        self.buffer = self.buffer.push_directive("DRIVER");
+        if let Some(value) = mode.value() {
+            self.buffer = self.buffer.push_value(value);
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `NOENTRY` directive. Builds a DLL without an entry
+    /// point. This is used for resource-only DLLs.
+    ///
+    /// # Safety
+    ///
+    /// The image has no entry point so any code that relies on being
+    /// initialized when the DLL is loaded will not run.
+    pub const unsafe fn no_entry(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("NOENTRY").push_seperator();
+        self
+    }
+    /// The `SWAPRUN` directive. Tells the loader to copy the image to the swap
+    /// file before running it from the given kind of media.
+    pub const fn swap_run(mut self, swap_run: SwapRun) -> Self {
+        self.buffer = self.buffer
+            .push_directive("SWAPRUN")
+            .push_value(swap_run.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `SWAPRUN` directive with multiple values combined into one
+    /// directive.
+    ///
+    /// Nothing is pushed if `swap_runs` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs, SwapRun};
+    ///
+    /// const SWAP_RUNS: &[SwapRun] = &[SwapRun::Cd, SwapRun::Net];
+    /// const ARGS: [u8; ArgSize::swap_run_all(SWAP_RUNS)] = LinkArgs::new()
+    ///     .swap_run_all(SWAP_RUNS)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/SWAPRUN:CD,NET ");
+    /// ```
+    pub const fn swap_run_all(mut self, swap_runs: &[SwapRun]) -> Self {
+        if swap_runs.is_empty() {
+            return self;
+        }
+        self.buffer = self.buffer
+            .push_directive("SWAPRUN")
+            .push_value(swap_runs[0].as_str());
+        let mut index = 1;
+        while index < swap_runs.len() {
+            self.buffer = self.buffer.push(b",").push(swap_runs[index].as_str().as_bytes());
+            index += 1;
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `FIXED` directive. Removes the relocation table so the
+    /// image can only be loaded at its preferred base address.
+    ///
+    /// `fixed(false)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// A fixed image can't be rebased so it conflicts with ASLR and will fail
+    /// to load if its base address is unavailable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     fixed(false);
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::fixed(true) + ArgSize::fixed(false)] = unsafe {
+    ///     LinkArgs::new().fixed(true).fixed(false).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/FIXED /FIXED:NO ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().fixed(true);
+    /// ```
+    pub const unsafe fn fixed(self, enabled: bool) -> Self {
+        self.toggle("FIXED", enabled)
+    }
+    /// The `ERRORREPORT` directive. Controls how internal linker errors
+    /// are reported to Microsoft.
+    ///
+    /// This only affects the linker itself, not the image it produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     error_report(Queue);
+    /// }
+    /// ```
+    pub const fn error_report(mut self, error_report: ErrorReport) -> Self {
+        self.buffer = self.buffer
+            .push_directive("ERRORREPORT")
+            .push_value(error_report.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `FORCE` directive with a value. Creates an image even if there are
+    /// the given kind of link errors.
+    ///
+    /// # Safety
+    ///
+    /// The resulting image may be broken. A multiply defined symbol may resolve
+    /// to the wrong definition, and calling an unresolved symbol will crash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     unsafe {
+    ///         force(Multiple);
+    ///     }
+    /// }
+    /// ```
+    pub const unsafe fn force(mut self, force_mode: ForceMode) -> Self {
+        self.buffer = self.buffer
+            .push_directive("FORCE")
+            .push_value(force_mode.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `FORCE` directive without a value. Creates an image even if there
+    /// are any link errors.
+    ///
+    /// # Safety
+    ///
+    /// The resulting image may be broken. A multiply defined symbol may
+    /// resolve to the wrong definition, and calling an unresolved symbol will
+    /// crash.
+    pub const unsafe fn force_all(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("FORCE").push_seperator();
+        self
+    }
+    /// The `INTEGRITYCHECK` directive. Requires a signature check when
+    /// the image is loaded.
+    ///
+    /// This sets the image's force integrity bit, which is required for some
+    /// code signing scenarios.
+    pub const fn integrity_check(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("INTEGRITYCHECK").push_seperator();
+        self
+    }
+    /// The `KERNEL` directive. Marks the image as kernel mode.
+    ///
+    /// This is usually paired with [`driver`](Self::driver) and
+    /// [`subsystem(Subsystem::Native)`](Self::subsystem).
+    ///
+    /// # Safety
+    ///
+    /// Code that expects to run in user mode will not work in kernel mode.
+    pub const unsafe fn kernel(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("KERNEL").push_seperator();
+        self
+    }
+    /// The `IGNORE` directive. Suppresses a linker warning.
+    ///
+    /// Each warning gets its own directive because not all versions of the
+    /// linker accept a list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     ignore_warning(4099, 4217);
+    /// }
+    /// ```
+    pub const fn ignore_warning(mut self, number: u32) -> Self {
+        self.buffer = self.buffer
+            .push_directive("IGNORE")
+            .push_value_dec(number)
+            .push_seperator();
+        self
+    }
+    /// The `LTCG` directive. Enables link time code generation.
+    ///
+    /// This is mostly useful alongside `-C lto` or when linking object files
+    /// produced by a C or C++ compiler with whole program optimization.
+    pub const fn ltcg(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("LTCG").push_seperator();
+        self
+    }
+    /// The `LTCG` directive with the `INCREMENTAL` value. Enables incremental
+    /// link time code generation.
+    ///
+    /// This is mostly useful alongside `-C lto` or when linking object files
+    /// produced by a C or C++ compiler with whole program optimization.
+    pub const fn ltcg_incremental(mut self) -> Self {
+        self.buffer = self.buffer
+            .push_directive("LTCG")
+            .push_value("INCREMENTAL")
+            .push_seperator();
+        self
+    }
+    /// The `LTCG` directive with the `OFF` value. Disables link time code
+    /// generation.
+    pub const fn ltcg_off(mut self) -> Self {
+        self.buffer = self.buffer
+            .push_directive("LTCG")
+            .push_value("OFF")
+            .push_seperator();
+        self
+    }
+    /// The `ORDER` directive. Lays out functions in the order listed in the
+    /// response file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::order("func order.txt")] = LinkArgs::new()
+    ///     .order("func order.txt")
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/ORDER:@\"func order.txt\" ");
+    /// ```
+    pub const fn order(mut self, response_file: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("ORDER")
+            .push(b":@")
+            .push_quoted(response_file)
+            .push_seperator();
+        self
+    }
+    /// The `HIGHENTROPYVA` directive. Allows ASLR to use the full 64-bit
+    /// address space.
+    ///
+    /// This requires [`dynamic_base`](Self::dynamic_base) to be enabled.
+    ///
+    /// `high_entropy_va(true)` can be used in the safe arm of the `windows!` macro.
+    /// Any other value must be used in an `unsafe` block.
+    ///
+    /// # Safety
+    ///
+    /// Disabling high entropy ASLR weakens a security mitigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     high_entropy_va(true);
+    /// }
+    /// ```
+    ///
+    /// The method is always `unsafe`, whichever value is used.
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::high_entropy_va(true) + ArgSize::high_entropy_va(false)] = unsafe {
+    ///     LinkArgs::new().high_entropy_va(true).high_entropy_va(false).into_exact_array()
+    /// };
+    /// assert_eq!(&ARGS, b"/HIGHENTROPYVA /HIGHENTROPYVA:NO ");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new().high_entropy_va(true);
+    /// ```
+    pub const unsafe fn high_entropy_va(self, enabled: bool) -> Self {
+        self.toggle("HIGHENTROPYVA", enabled)
+    }
+    /// The `APPCONTAINER` directive. Marks the image as one that must
+    /// run in an app container, such as a UWP app.
+    ///
+    /// The loader will refuse to start an app container image outside of an
+    /// app container, and the process will be sandboxed with restricted access
+    /// to files, the registry and other system resources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     app_container(true);
+    /// }
+    /// ```
+    pub const fn app_container(self, enabled: bool) -> Self {
+        self.toggle("APPCONTAINER", enabled)
+    }
+    /// The `MANIFESTUAC` directive. Sets the User Account Control information
+    /// in the embedded manifest.
+    ///
+    /// `level` is one of `asInvoker`, `highestAvailable` or
+    /// `requireAdministrator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is not one of the above. When used in a `const` this
+    /// fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const SIZE: usize = ArgSize::manifest_uac("requireAdministrator", false);
+    /// const ARGS: [u8; SIZE] = LinkArgs::new()
+    ///     .manifest_uac("requireAdministrator", false)
+    ///     .into_array();
+    /// assert_eq!(
+    ///     &ARGS,
+    ///     b"/MANIFESTUAC:\"level='requireAdministrator' uiAccess='false'\" ",
+    /// );
+    /// ```
+    ///
+    /// Other levels are rejected, which also stops a level from ending the
+    /// quoted value early.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const LEVEL: &str = "asInvoker' uiAccess='true";
+    /// const ARGS: [u8; ArgSize::manifest_uac(LEVEL, false)] = LinkArgs::new()
+    ///     .manifest_uac(LEVEL, false)
+    ///     .into_array();
+    /// ```
+    pub const fn manifest_uac(mut self, level: &str, ui_access: bool) -> Self {
+        let level_bytes = level.as_bytes();
+        if !bytes_eq(level_bytes, b"asInvoker")
+            && !bytes_eq(level_bytes, b"highestAvailable")
+            && !bytes_eq(level_bytes, b"requireAdministrator")
+        {
+            panic!("link_args: the `MANIFESTUAC` level must be `asInvoker`, `highestAvailable` or `requireAdministrator`");
+        }
+        let ui_access: &[u8] = if ui_access { b"true" } else { b"false" };
+        self.buffer = self.buffer
+            .push_directive("MANIFESTUAC")
+            .push(b":\"level='")
+            .push(level.as_bytes())
+            .push(b"' uiAccess='")
+            .push(ui_access)
+            .push(b"'\"")
+            .push_seperator();
+        self
+    }
+    /// The `MANIFEST` directive. Sets how a manifest is created.
+    ///
+    /// Embedding the manifest is the common case for modern applications.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     manifest(Embed);
+    /// }
+    /// ```
+    pub const fn manifest(mut self, manifest_mode: ManifestMode) -> Self {
+        self.buffer = self.buffer.push_directive("MANIFEST");
+        if let Some(value) = manifest_mode.value() {
+            self.buffer = self.buffer.push_value(value);
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `MANIFESTINPUT` directive. Adds a manifest file to merge into the
+    /// embedded manifest.
+    ///
+    /// This only works when combined with `/MANIFEST:EMBED`, otherwise the
+    /// linker will raise an error. See [`embed_manifest`](Self::embed_manifest)
+    /// to set both at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     manifest_input("app.manifest");
+    /// }
+    /// ```
+    pub const fn manifest_input(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("MANIFESTINPUT")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// Embeds the manifest and merges in the manifest file at `path`.
+    ///
+    /// This is the same as setting both `/MANIFEST:EMBED` and
+    /// `/MANIFESTINPUT`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const SIZE: usize = ArgSize::embed_manifest("app.manifest");
+    /// const ARGS: [u8; SIZE] = LinkArgs::new()
+    ///     .embed_manifest("app.manifest")
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/MANIFEST:EMBED /MANIFESTINPUT:\"app.manifest\" ");
+    /// ```
+    pub const fn embed_manifest(self, path: &str) -> Self {
+        self.manifest(ManifestMode::Embed).manifest_input(path)
+    }
+    /// The `VERBOSE` directive. Displays diagnostic information while linking.
+    ///
+    /// This can be useful to find out which default libraries are actually used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     verbose(Lib);
+    /// }
+    /// ```
+    pub const fn verbose(mut self, verbose: Verbose) -> Self {
+        self.buffer = self.buffer.push_directive("VERBOSE");
+        if let Some(value) = verbose.value() {
+            self.buffer = self.buffer.push_value(value);
+        }
+        self.buffer = self.buffer.push_seperator();
+        self
+    }
+    /// The `VERBOSE` directive. Displays all progress messages while linking.
+    ///
+    /// This is the same as `verbose(Verbose::All)`.
+    pub const fn verbose_all(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("VERBOSE").push_seperator();
+        self
+    }
+    /// The `NOLOGO` directive. Suppresses the linker's startup banner.
+    pub const fn no_logo(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("NOLOGO").push_seperator();
+        self
+    }
+    /// The `INCREMENTAL` directive. Enables or disables incremental linking.
+    ///
+    /// Disabling incremental linking is common for release builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     incremental(false);
+    /// }
+    /// ```
+    pub const fn incremental(self, enabled: bool) -> Self {
+        self.toggle("INCREMENTAL", enabled)
+    }
+    /// The `FILEALIGN` directive. Sets the alignment of sections within the
+    /// output file.
+    ///
+    /// `bytes` must be a power of two. A smaller alignment can reduce the size
+    /// of small binaries on disk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     file_align(512);
+    /// }
+    /// ```
+    ///
+    /// Using an alignment that isn't a power of two fails to compile.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const ARGS: [u8; ArgSize::file_align(500)] = LinkArgs::new().file_align(500).into_array();
+    /// ```
+    pub const fn file_align(mut self, bytes: u32) -> Self {
+        if !bytes.is_power_of_two() {
+            panic!("link_args: the FILEALIGN value must be a power of two");
+        }
+        self.buffer = self.buffer
+            .push_directive("FILEALIGN")
+            .push_value_dec(bytes)
+            .push_seperator();
+        self
+    }
+    /// The `WX` directive. Treats linker warnings as errors.
+    ///
+    /// This is useful for catching conflicting `DEFAULTLIB` directives early.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     warnings_as_errors(true);
+    /// }
+    /// ```
+    pub const fn warnings_as_errors(self, enabled: bool) -> Self {
+        self.toggle("WX", enabled)
+    }
+    /// The `LINKREPRO` directive. Writes a link repro to the given directory.
+    ///
+    /// The directory will contain everything needed to reproduce the link, which
+    /// is useful for reporting linker bugs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     link_repro("repro");
+    /// }
+    /// ```
+    pub const fn link_repro(mut self, dir: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("LINKREPRO")
+            .push_value_quoted(dir)
+            .push_seperator();
+        self
     }
-}
-
-/// Helps to construct MSVC linker arguments.
-pub struct LinkArgs<const CAPACITY: usize> {
-    buffer: Buffer::<CAPACITY>
-}
-impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
-    /// The `STACK` directive.
+    /// The `EMITPOGOPHASEINFO` directive. Emits information about the profile
+    /// guided optimization phase.
     ///
-    /// `reserve` is the number of bytes of virtual memory to reserve for the
-    /// stack.
-    pub const fn stack_size(mut self, reserve: u32) -> Self {
+    /// This is useful alongside `/GENPROFILE` and `/USEPROFILE` when using
+    /// profile guided optimization.
+    pub const fn emit_pogo_phase_info(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("EMITPOGOPHASEINFO").push_seperator();
+        self
+    }
+    /// The `LIBPATH` directive. Adds a directory to search for libraries.
+    ///
+    /// The directory is searched before the `LIB` environment path. This pairs
+    /// well with [`default_lib`](Self::default_lib) for libraries in
+    /// non-standard locations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     lib_path("C:\\Program Files\\MyLib\\lib", "vendor\\lib");
+    /// }
+    /// ```
+    pub const fn lib_path(mut self, path: &str) -> Self {
         self.buffer = self.buffer
-            .push_directive("STACK")
-            .push_value_hex(reserve)
+            .push_directive("LIBPATH")
+            .push_value_quoted(path)
             .push_seperator();
         self
     }
-    /// The `STACK` directive with explicit commit value.
+    /// The `DLL` directive. Builds a DLL as the main output file.
     ///
-    /// `reserve` is the number of bytes of virtual memory to reserve for the
-    /// stack. `commit` is the number of byte of physical memory to allocate for
-    /// the stack when the program starts.
-    pub const fn stack_size_with_commit(mut self, reserve: u32, commit: u32) -> Self {
+    /// This can be useful when a single crate may be built in multiple ways.
+    ///
+    /// # Safety
+    ///
+    /// This fundamentally changes the kind of output that is produced. Rust
+    /// normally decides this using the crate type so the rest of the build
+    /// must expect a DLL.
+    pub const unsafe fn dll(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("DLL").push_seperator();
+        self
+    }
+    /// The `KEYFILE` directive. Sets the file containing the key used to strong
+    /// name an assembly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     key_file("key.snk");
+    /// }
+    /// ```
+    pub const fn key_file(mut self, path: &str) -> Self {
         self.buffer = self.buffer
-            .push_directive("STACK")
-            .push_values_hex(&[reserve, commit])
+            .push_directive("KEYFILE")
+            .push_value_quoted(path)
             .push_seperator();
         self
     }
-    /// The `DEFAULTLIB` directive. Adds a library to use.
-    /// 
-    /// Libraries specified on the command line will override default libraries if
-    /// there is a conflict.
-    pub const fn default_lib(mut self, lib: &str) -> Self {
+    /// The `KEYCONTAINER` directive. Sets the key container used to strong name
+    /// an assembly.
+    ///
+    /// The name is only quoted if it contains a space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     key_container("MyContainer");
+    /// }
+    /// ```
+    pub const fn key_container(mut self, name: &str) -> Self {
         self.buffer = self.buffer
-            .push_directive("DEFAULTLIB")
-            .push_value_quoted(lib)
+            .push_directive("KEYCONTAINER")
+            .push_value_maybe_quoted(name)
             .push_seperator();
         self
     }
-    /// The `NODEFAULTLIB` directive. Prevents a default library from being used.
+    /// The `DELAYSIGN` directive. Partially signs an assembly so that it can be
+    /// fully signed later.
     ///
-    /// Overrides `default_lib`. Libraries specified on the command line will
-    /// bypass `no_default_lib`.
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     delay_sign(true);
+    /// }
+    /// ```
+    pub const fn delay_sign(self, enabled: bool) -> Self {
+        self.toggle("DELAYSIGN", enabled)
+    }
+    /// The `TLBOUT` directive. Sets the name of the generated type library.
+    ///
+    /// This is used by COM components.
     ///
-    /// This can be unsafe if used with `default_lib` to replace symbols.
-    /// 
     /// # Examples
     ///
-    /// Prevent kernel32 from being linked.
+    /// ```rust
+    /// link_args::windows! {
+    ///     tlb_out("component.tlb");
+    /// }
+    /// ```
+    pub const fn tlb_out(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("TLBOUT")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `TLBID` directive. Sets the resource ID of the generated type
+    /// library.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(not(debug_assertions))]
-    /// link_args::windows::no_default_lib!("kernel32");
+    /// link_args::windows! {
+    ///     tlb_id(2);
+    /// }
     /// ```
-    pub const unsafe fn no_default_lib(mut self, lib: &str) -> Self {
+    pub const fn tlb_id(mut self, id: u16) -> Self {
         self.buffer = self.buffer
-            .push_directive("NODEFAULTLIB")
-            .push_value_quoted(lib)
+            .push_directive("TLBID")
+            .push_value_dec(id as u32)
             .push_seperator();
         self
     }
-    /// The `NODEFAULTLIB` directive wihout arguments. Prevent any default lib
-    /// from being used.
+    /// The `WINMD` directive. Enables or disables generating Windows Runtime
+    /// metadata.
     ///
-    /// Completely disables any and all use of `default_lib`. This is safe
-    /// because if a symbol is unavaliable the program will fail to compile.
-    pub const fn disable_all_default_libs(mut self) -> Self {
-        self.buffer = self.buffer.push_directive("NODEFAULTLIB").push_seperator();
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     winmd(true);
+    /// }
+    /// ```
+    pub const fn winmd(self, enabled: bool) -> Self {
+        self.toggle("WINMD", enabled)
+    }
+    /// The `WINMDFILE` directive. Sets the name of the Windows Runtime metadata
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     winmd_file("My Component.winmd");
+    /// }
+    /// ```
+    pub const fn winmd_file(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("WINMDFILE")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `SOURCELINK` directive. Embeds a Source Link JSON file into the PDB.
+    ///
+    /// This only affects debug information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     source_link("sourcelink.json");
+    /// }
+    /// ```
+    pub const fn source_link(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("SOURCELINK")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `PDBSTRIPPED` directive. Creates a second PDB without private symbols.
+    ///
+    /// The stripped PDB is suitable for distributing to others.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     pdb_stripped("app_public.pdb");
+    /// }
+    /// ```
+    pub const fn pdb_stripped(mut self, path: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("PDBSTRIPPED")
+            .push_value_quoted(path)
+            .push_seperator();
+        self
+    }
+    /// The `GENPROFILE` directive. Generates a profile for profile guided
+    /// optimization.
+    ///
+    /// Profile guided optimization typically requires link time code
+    /// generation, see [`ltcg`](Self::ltcg).
+    pub const fn gen_profile(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("GENPROFILE").push_seperator();
+        self
+    }
+    /// The `USEPROFILE` directive. Uses a profile for profile guided optimization.
+    ///
+    /// Profile guided optimization typically requires link time code
+    /// generation, see [`ltcg`](Self::ltcg).
+    pub const fn use_profile(mut self) -> Self {
+        self.buffer = self.buffer.push_directive("USEPROFILE").push_seperator();
+        self
+    }
+    /// The `GENPROFILE` directive. Generates a profile for profile guided
+    /// optimization, using the given `.pgd` file.
+    ///
+    /// Profile guided optimization typically requires link time code
+    /// generation, see [`ltcg`](Self::ltcg).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     ltcg();
+    ///     gen_profile_to("app.pgd");
+    /// }
+    /// ```
+    pub const fn gen_profile_to(mut self, pgd: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("GENPROFILE")
+            .push(b":PGD=")
+            .push_quoted(pgd)
+            .push_seperator();
+        self
+    }
+    /// The `USEPROFILE` directive. Uses a profile from the given `.pgd` file for
+    /// profile guided optimization.
+    ///
+    /// Profile guided optimization typically requires link time code
+    /// generation, see [`ltcg`](Self::ltcg).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     ltcg();
+    ///     use_profile_from("app.pgd");
+    /// }
+    /// ```
+    pub const fn use_profile_from(mut self, pgd: &str) -> Self {
+        self.buffer = self.buffer
+            .push_directive("USEPROFILE")
+            .push(b":PGD=")
+            .push_quoted(pgd)
+            .push_seperator();
+        self
+    }
+    /// The `CLRIMAGETYPE` directive. Sets the kind of CLR image to create.
+    ///
+    /// This is useful for C++/CLI interop binaries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     clr_image_type(IjwOnly);
+    /// }
+    /// ```
+    pub const fn clr_image_type(mut self, clr_image_type: ClrImageType) -> Self {
+        self.buffer = self.buffer
+            .push_directive("CLRIMAGETYPE")
+            .push_value(clr_image_type.as_str())
+            .push_seperator();
+        self
+    }
+    /// The `CLRUNMANAGEDCODECHECK` directive. Enables or disables
+    /// unmanaged code security checks on the PInvoke stubs that the linker
+    /// generates. When disabled, `SuppressUnmanagedCodeSecurity` is applied to
+    /// the stubs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     clr_unmanaged_code_check(false);
+    /// }
+    /// ```
+    pub const fn clr_unmanaged_code_check(self, enabled: bool) -> Self {
+        self.toggle("CLRUNMANAGEDCODECHECK", enabled)
+    }
+    /// The `DEBUGTYPE` directive. Sets what kinds of debug information are
+    /// included in the PDB.
+    ///
+    /// At least one type must be set. This is used along with the
+    /// [`debug`](Self::debug) directive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     debug(Full);
+    ///     debug_type(CV, FIXUP);
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, DebugType, LinkArgs};
+    ///
+    /// const TYPES: DebugType = DebugType::CV.union(DebugType::FIXUP);
+    /// const ARGS: [u8; ArgSize::debug_type(TYPES)] = LinkArgs::new()
+    ///     .debug_type(TYPES)
+    ///     .into_array();
+    /// assert_eq!(&ARGS, b"/DEBUGTYPE:CV,FIXUP ");
+    /// ```
+    pub const fn debug_type(mut self, types: DebugType) -> Self {
+        if types.bits() == 0 {
+            panic!("link_args: the DEBUGTYPE directive needs at least one type");
+        }
+        self.buffer = self.buffer.push_directive("DEBUGTYPE").push(b":");
+        let mut first = true;
+        let mut index = 0;
+        while index < DebugType::NAMES.len() {
+            let (flag, name) = DebugType::NAMES[index];
+            if types.contains(flag) {
+                if !first {
+                    self.buffer = self.buffer.push(b",");
+                }
+                self.buffer = self.buffer.push(name.as_bytes());
+                first = false;
+            }
+            index += 1;
+        }
+        self.buffer = self.buffer.push_seperator();
         self
     }
-    /// One or more raw arguments, seperated by a space.
+    /// One or more raw arguments, separated by a space.
     ///
     /// Many arguments that work on the command line will not work here.
     ///
+    /// # Safety
+    ///
+    /// The arguments are passed to the linker unchecked. It is up to the caller
+    /// to make sure they don't produce an unsound binary.
+    ///
     /// # Examples
     ///
     /// ## The `windows!` macro.
@@ -108,6 +3185,16 @@ impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
     /// }
     /// ```
     ///
+    /// Multiple strings can be given at once.
+    ///
+    /// ```rust
+    /// link_args::windows!{
+    ///     unsafe {
+    ///         raw("/ENTRY:mainCRTStartup", "/STACK:0x800000");
+    ///     }
+    /// }
+    /// ```
+    ///
     /// ## The `windows::raw!` macro.
     ///
     /// ```rust
@@ -137,19 +3224,149 @@ impl<const CAPACITY: usize> LinkArgs<CAPACITY> {
         self.buffer = self.buffer.push(raw.as_bytes()).push_seperator();
         self
     }
-
-    /// Create an empty argument list with the `CAPACITY` of the type.
-    pub const fn new() -> Self {
-        Self {
-            buffer: Buffer::new()
+    /// The same as [`raw`](Self::raw) but takes the arguments as bytes.
+    ///
+    /// # Safety
+    ///
+    /// The arguments are passed to the linker unchecked. It is up to the caller
+    /// to make sure they don't produce an unsound binary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     unsafe {
+    ///         raw_bytes(b"/ENTRY:mainCRTStartup", b"/NOLOGO");
+    ///     }
+    /// }
+    /// ```
+    pub const unsafe fn raw_bytes(mut self, raw: &[u8]) -> Self {
+        self.buffer = self.buffer.push(raw).push_seperator();
+        self
+    }
+    /// Fails if a directive that should only be set once is set more than once.
+    ///
+    /// The linker's behaviour when given the same directive twice depends on its
+    /// version so this turns it into an error. Only `STACK`, `HEAP`, `ENTRY`
+    /// and `SUBSYSTEM` are checked. Arguments added after this are not checked.
+    ///
+    /// In a [`windows!`](crate::windows!) block use `check_conflicts()` after
+    /// the other arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the checked directives is repeated. When used in a
+    /// `const` this fails to compile.
+    ///
+    /// ```compile_fail
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// const ARGS: LinkArgs<64> = LinkArgs::new()
+    ///     .stack_size(0x800000)
+    ///     .stack_size(0x400000)
+    ///     .check_conflicts();
+    /// # let _ = ARGS.len();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// link_args::windows! {
+    ///     stack_size(0x800000);
+    ///     heap_size(0x100000);
+    ///     check_conflicts();
+    /// }
+    /// ```
+    pub const fn check_conflicts(self) -> Self {
+        const DIRECTIVES: [&str; 4] = ["STACK", "HEAP", "ENTRY", "SUBSYSTEM"];
+        const ERRORS: [&str; 4] = [
+            "link_args: conflicting arguments, /STACK is set more than once",
+            "link_args: conflicting arguments, /HEAP is set more than once",
+            "link_args: conflicting arguments, /ENTRY is set more than once",
+            "link_args: conflicting arguments, /SUBSYSTEM is set more than once",
+        ];
+        let bytes = &self.buffer.buffer;
+        let len = self.buffer.len;
+        let mut seen = [false; DIRECTIVES.len()];
+        let mut in_quotes = false;
+        let mut index = 0;
+        while index < len {
+            let is_start = index == 0 || (bytes[index - 1] == b' ' && !in_quotes);
+            if is_start && bytes[index] == b'/' {
+                let mut end = index + 1;
+                while end < len && bytes[end] != b':' && bytes[end] != b' ' {
+                    end += 1;
+                }
+                let mut directive = 0;
+                while directive < DIRECTIVES.len() {
+                    if is_directive_at(bytes, index + 1, end, DIRECTIVES[directive]) {
+                        if seen[directive] {
+                            panic!("{}", ERRORS[directive]);
+                        }
+                        seen[directive] = true;
+                    }
+                    directive += 1;
+                }
+            }
+            if bytes[index] == b'"' {
+                in_quotes = !in_quotes;
+            }
+            index += 1;
         }
+        self
     }
-    /// Get the length in bytes.
-    pub const fn len(&self) -> usize {
-        self.buffer.len
+    /// Appends the arguments from `other` onto the end of these arguments.
+    ///
+    /// This allows reusable groups of arguments to be combined. The `CAPACITY`
+    /// must be large enough to hold both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::{ArgSize, LinkArgs};
+    ///
+    /// const LIBS_SIZE: usize = ArgSize::default_lib("kernel32.lib");
+    /// const LIBS: LinkArgs<LIBS_SIZE> = LinkArgs::new().default_lib("kernel32.lib");
+    ///
+    /// const STACK_SIZE: usize = ArgSize::stack_size(0x800000);
+    /// const STACK: LinkArgs<STACK_SIZE> = LinkArgs::new().stack_size(0x800000);
+    ///
+    /// const ARGS: LinkArgs<{ LIBS_SIZE + STACK_SIZE }> = LinkArgs::new()
+    ///     .concat(LIBS)
+    ///     .concat(STACK);
+    /// assert_eq!(ARGS.len(), LIBS_SIZE + STACK_SIZE);
+    /// assert_eq!(ARGS.as_str(), "/DEFAULTLIB:\"kernel32.lib\" /STACK:0x800000 ");
+    /// ```
+    pub const fn concat<const N: usize>(mut self, other: LinkArgs<N>) -> Self {
+        self.buffer = self.buffer.push_buffer(other.buffer);
+        self
     }
-    /// Consume the `LinkArgs` and return its byte buffer.
-    pub const fn into_array(self) -> [u8; CAPACITY] {
-        self.buffer.buffer
+
+    /// An iterator over each argument.
+    ///
+    /// Arguments are separated by spaces, except for spaces within quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use link_args::windows::msvc::LinkArgs;
+    ///
+    /// let args = LinkArgs::<64>::new()
+    ///     .stack_size(0x800000)
+    ///     .default_lib("C:\\My Libs\\mylib.lib");
+    /// let mut iter = args.iter();
+    /// assert_eq!(iter.next(), Some("/STACK:0x800000"));
+    /// assert_eq!(iter.next(), Some("/DEFAULTLIB:\"C:\\My Libs\\mylib.lib\""));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> ArgsIter<'_> {
+        ArgsIter { rest: self.as_str() }
     }
 }
+
+impl_link_args_common!(
+    "link_args::windows::msvc",
+    ".no_logo()",
+    "ArgSize::NO_LOGO",
+    "/NOLOGO ",
+);
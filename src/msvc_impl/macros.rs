@@ -41,9 +41,20 @@ macro_rules! windows_raw {
 /// This will not check for errors such as invalid arguments.
 /// The bytes should end with a space (` `) otherwise to seperate it from any
 /// further arguments that may be added.
+///
+/// With a leading `static` the static is named so it can be used with
+/// [`StaticDirective::bytes`](crate::windows::msvc::StaticDirective::bytes).
+#[cfg(not(feature = "used_linker"))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! impl_msvc_bytes {
+    (static $vis:vis $name:ident, $size:expr, $bytes:expr) => {
+        #[cfg(all(windows, target_env = "msvc"))]
+        #[link_section = ".drectve"]
+        #[used]
+        $vis static $name: $crate::windows::msvc::StaticDirective<{ $size }> =
+            $crate::windows::msvc::StaticDirective::new($bytes);
+    };
     ($size:expr, $bytes:expr) => {
         const _: () = {
             // This cfg restraint can be loosend if we support another target_env.
@@ -55,56 +66,594 @@ macro_rules! impl_msvc_bytes {
     };
 }
 
-/// Set how much virtual memory is avaliable for the stack.
-///
-/// You can also optionally allocate physical memory upfront. Be aware that
-/// Rust's `std::thread` can and will override these settings for all but the
-/// main thread.
-///
-/// # Examples
+/// The same as above but tells the compiler that the linker must keep the
+/// static. This requires `#![feature(used_with_arg)]` in the calling crate.
+#[cfg(feature = "used_linker")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_bytes {
+    (static $vis:vis $name:ident, $size:expr, $bytes:expr) => {
+        #[cfg(all(windows, target_env = "msvc"))]
+        #[link_section = ".drectve"]
+        #[used(linker)]
+        $vis static $name: $crate::windows::msvc::StaticDirective<{ $size }> =
+            $crate::windows::msvc::StaticDirective::new($bytes);
+    };
+    ($size:expr, $bytes:expr) => {
+        const _: () = {
+            #[cfg(all(windows, target_env = "msvc"))]
+            #[link_section = ".drectve"]
+            #[used(linker)]
+            static DIRECTIVE: [u8; $size] = $bytes;
+        };
+    };
+}
+
+/// Embeds raw linker arguments for Windows targets from a file.
 ///
-/// Reserve 8 MiB of virtual memory for the stack.
+/// The file is read at compile time using [`include_str!`] so the path is
+/// relative to the file the macro is used in. Each line can have one or more
+/// arguments. Newlines are treated as spaces and trailing newlines are trimmed.
 ///
-/// ```rust
-/// link_args::windows::stack_size!(0x800000);
-/// ```
+/// As with [`raw!`](crate::windows::raw) the arguments are not checked.
 ///
-/// Reserve 8 MiB for the stack and allocate 4 MiB as soon as the program starts.
+/// # Example
 ///
 /// ```rust
-/// link_args::windows::stack_size!(0x800000, 0x400000);
+/// // The path is relative to the file containing this example.
+/// link_args::windows::raw_from_file!(unsafe "../../examples/args.linkargs");
 /// ```
 #[macro_export]
-macro_rules! windows_msvc_stack_size {
-    ($reserve:expr) => {
-        const _: () = {
+macro_rules! windows_raw_from_file {
+    (unsafe $path:expr) => {
+        #[cfg(windows)]
+        const _:() = {
+            enum ns {}
+            impl ns {
+                const raw_args: &'static [u8] = ::core::include_str!($path).as_bytes();
+                const len: usize = {
+                    let mut len = ns::raw_args.len();
+                    while len > 0 && (ns::raw_args[len - 1] == b'\n' || ns::raw_args[len - 1] == b'\r') {
+                        len -= 1;
+                    }
+                    len
+                };
+                const args: [u8; ns::len + 1] = {
+                    let mut bytes = [b' '; ns::len + 1];
+                    let mut index = 0;
+                    while index < ns::len {
+                        let byte = ns::raw_args[index];
+                        if byte != b'\n' && byte != b'\r' {
+                            bytes[index] = byte;
+                        }
+                        index += 1;
+                    }
+                    bytes
+                };
+            }
             $crate::impl_msvc_bytes!(
-                $crate::windows::msvc::ArgSize::STACK_SIZE,
-                $crate::windows::msvc::LinkArgs::new().stack_size($reserve).into_array()
+                ns::args.len(),
+                ns::args
             );
         };
     };
-    ($reserve:expr, $commit:expr) => {
-        const _: () = {
-            $crate::impl_msvc_bytes!(
-                $crate::windows::msvc::ArgSize::STACK_SIZE_WITH_COMMIT,
-                $crate::windows::msvc::LinkArgs::new().stack_size_with_commit($reserve, $commit).into_array()
-            );
-        };
+}
+
+/// Sets a single argument using `windows!`.
+///
+/// All the `windows::NAME!` macros forward here so they always accept the
+/// same arguments as `windows!`. A leading `unsafe` keyword uses the unsafe
+/// form.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_absolute {
+    ($name:ident(unsafe $($args:tt)*)) => {
+        $crate::windows! {
+            unsafe {
+                $name($($args)*);
+            }
+        }
+    };
+    ($name:ident($($args:tt)*)) => {
+        $crate::windows! {
+            $name($($args)*);
+        }
     };
 }
 
-/// Adds one or more default libraries.
+/// Defines one `windows::NAME!` macro, which forwards to `impl_msvc_absolute!`.
 ///
-/// Default libraries will be used to find symbols when they are not found in
-/// libraries specified on the command line.
+/// A macro defined by another macro can't be re-exported by its path so the
+/// re-export has to be made here, where the new macro is in scope. `$d` is a
+/// `$` token that is used to write the new macro's own matcher.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! windows_msvc_default_lib {
-    ($($lib:expr),+) => {
-        $crate::impl_msvc_bytes!(
-            $crate::impl_msvc_arg_size!(default_lib($($lib),+)),
-            $crate::impl_msvc_args!($crate::windows::msvc::LinkArgs::new(), default_lib($($lib),+)).into_array()
-        );
+macro_rules! impl_msvc_absolute_macro {
+    ($d:tt $(#[$($attr:tt)*])+ $name:ident => $full:ident) => {
+        $(#[$($attr)*])+
+        #[macro_export]
+        macro_rules! $full {
+            ($d($d args:tt)*) => {
+                $crate::impl_msvc_absolute!($name($d($d args)*));
+            };
+        }
+        #[doc(inline)]
+        pub use $full as $name;
+    };
+    ($d:tt $name:ident => $full:ident) => {
+        $crate::impl_msvc_absolute_macro! {
+            $d
+            #[doc = concat!(
+                "Sets the [`", stringify!($name), "`](crate::windows::msvc::LinkArgs::",
+                stringify!($name), ") linker argument."
+            )]
+            #[doc = ""]
+            #[doc = concat!(
+                "This takes the same arguments as `", stringify!($name),
+                "` in [`windows!`](crate::windows!), including the `unsafe` keyword ",
+                "when it's required."
+            )]
+            $name => $full
+        }
+    };
+}
+
+/// Defines all the `windows::NAME!` macros. This is used in the `windows`
+/// module, which passes in a `$` token.
+///
+/// Macros without their own documentation link to the `LinkArgs` method of the
+/// same name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_absolute_macros {
+    ($d:tt) => {
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Set how much virtual memory is avaliable for the stack.
+            ///
+            /// You can also optionally allocate physical memory upfront. Be aware that
+            /// Rust's `std::thread` can and will override these settings for all but the
+            /// main thread.
+            ///
+            /// # Examples
+            ///
+            /// Reserve 8 MiB of virtual memory for the stack.
+            ///
+            /// ```rust
+            /// link_args::windows::stack_size!(0x800000);
+            /// ```
+            ///
+            /// Reserve 8 MiB for the stack and allocate 4 MiB as soon as the program starts.
+            ///
+            /// ```rust
+            /// link_args::windows::stack_size!(0x800000, 0x400000);
+            /// ```
+            stack_size => windows_msvc_stack_size
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Set how much virtual memory is avaliable for the heap.
+            ///
+            /// You can also optionally allocate physical memory upfront.
+            ///
+            /// # Examples
+            ///
+            /// Reserve 1 MiB of virtual memory for the heap.
+            ///
+            /// ```rust
+            /// link_args::windows::heap_size!(0x100000);
+            /// ```
+            ///
+            /// Reserve 1 MiB for the heap and allocate 64 KiB as soon as the program starts.
+            ///
+            /// ```rust
+            /// link_args::windows::heap_size!(0x100000, 0x10000);
+            /// ```
+            heap_size => windows_msvc_heap_size
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Adds one or more default libraries.
+            ///
+            /// Default libraries will be used to find symbols when they are not found in
+            /// libraries specified on the command line.
+            default_lib => windows_msvc_default_lib
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the entry point of the image.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::entry!("mainCRTStartup");
+            /// ```
+            entry => windows_msvc_entry
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Forces one or more symbols to be included in the image.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::include!("my_static");
+            /// ```
+            include => windows_msvc_include
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the preferred base address of the image.
+            ///
+            /// This is unsafe because an unsuitable base address can make the image
+            /// unloadable. See [`LinkArgs::base`](crate::windows::msvc::LinkArgs::base).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::base!(unsafe 0x140000000);
+            /// ```
+            base => windows_msvc_base
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Stamps a `major.minor` version number into the image header.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::version!(1, 2);
+            /// ```
+            version => windows_msvc_version
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Controls the generation of debug information.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::debug!(FastLink);
+            /// ```
+            debug => windows_msvc_debug
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Controls whether the image can be randomly rebased at load time (ASLR).
+            ///
+            /// Disabling ASLR is unsafe so requires the `unsafe` keyword.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::dynamic_base!(true);
+            /// ```
+            ///
+            /// ```rust
+            /// link_args::windows::dynamic_base!(unsafe false);
+            /// ```
+            dynamic_base => windows_msvc_dynamic_base
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Marks the image as compatible with Data Execution Prevention (DEP).
+            ///
+            /// Disabling DEP is unsafe so requires the `unsafe` keyword.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::nx_compat!(true);
+            /// ```
+            ///
+            /// ```rust
+            /// link_args::windows::nx_compat!(unsafe false);
+            /// ```
+            nx_compat => windows_msvc_nx_compat
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Marks the application as aware of Terminal Server (Remote Desktop).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::ts_aware!(true);
+            /// ```
+            ts_aware => windows_msvc_ts_aware
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Marks the image as compatible with Control-flow Enforcement Technology (CET)
+            /// shadow stacks.
+            ///
+            /// Disabling CET is unsafe so requires the `unsafe` keyword.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::cet_compat!(true);
+            /// ```
+            ///
+            /// ```rust
+            /// link_args::windows::cet_compat!(unsafe false);
+            /// ```
+            cet_compat => windows_msvc_cet_compat
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the `LoadLibraryEx` flags used when loading the image's dependencies.
+            ///
+            /// This is unsafe so requires the `unsafe` keyword. See
+            /// [`LinkArgs::dependent_load_flag`](crate::windows::msvc::LinkArgs::dependent_load_flag).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::dependent_load_flag!(unsafe 0x800);
+            /// ```
+            dependent_load_flag => windows_msvc_dependent_load_flag
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the checksum in the image header.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::release!();
+            /// ```
+            release => windows_msvc_release
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Produces an image that can be used with the Performance Tools profiler.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::profile!();
+            /// ```
+            profile => windows_msvc_profile
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Produces a table of the image's safe exception handlers.
+            ///
+            /// This is only emitted for x86 targets. Disabling safe exception handlers is
+            /// unsafe so requires the `unsafe` keyword.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::safe_seh!(true);
+            /// ```
+            ///
+            /// ```rust
+            /// link_args::windows::safe_seh!(unsafe false);
+            /// ```
+            safe_seh => windows_msvc_safe_seh
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the target platform of the image using the `target_arch` being compiled
+            /// for.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::machine_from_target!();
+            /// ```
+            machine_from_target => windows_msvc_machine_from_target
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Embeds a debugger visualization file (`.natvis`) in the PDB.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::natvis!("my_types.natvis");
+            /// ```
+            natvis => windows_msvc_natvis
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets the name of the import library generated for a DLL.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::impl_lib!("my_lib.lib");
+            /// ```
+            impl_lib => windows_msvc_impl_lib
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Replaces the default MS-DOS stub program at the start of the image.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::stub!("dos_stub.exe");
+            /// ```
+            stub => windows_msvc_stub
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Builds a DLL without an entry point. This is used for resource-only DLLs.
+            ///
+            /// This is unsafe so requires the `unsafe` keyword. See
+            /// [`LinkArgs::no_entry`](crate::windows::msvc::LinkArgs::no_entry).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::no_entry!(unsafe);
+            /// ```
+            no_entry => windows_msvc_no_entry
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Removes the relocation table so the image can only be loaded at its
+            /// preferred base address.
+            ///
+            /// Fixing the image is unsafe so requires the `unsafe` keyword.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::fixed!(false);
+            /// ```
+            ///
+            /// ```rust
+            /// link_args::windows::fixed!(unsafe true);
+            /// ```
+            fixed => windows_msvc_fixed
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Controls how internal linker errors are reported to Microsoft.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::error_report!(Queue);
+            /// ```
+            error_report => windows_msvc_error_report
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Requires a signature check when the image is loaded.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::integrity_check!();
+            /// ```
+            integrity_check => windows_msvc_integrity_check
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Suppresses one or more linker warnings.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::ignore_warning!(4099);
+            /// ```
+            ignore_warning => windows_msvc_ignore_warning
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Lays out functions in the order listed in a response file.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::order!("function_order.txt");
+            /// ```
+            order => windows_msvc_order
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Sets how the linker creates the manifest.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::manifest!(Embed);
+            /// ```
+            manifest => windows_msvc_manifest
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Suppresses the linker's startup banner.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::no_logo!();
+            /// ```
+            no_logo => windows_msvc_no_logo
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Enables or disables incremental linking.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::incremental!(false);
+            /// ```
+            incremental => windows_msvc_incremental
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Treats linker warnings as errors.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::warnings_as_errors!(true);
+            /// ```
+            warnings_as_errors => windows_msvc_warnings_as_errors
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Embeds a Source Link JSON file into the PDB.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::source_link!("sourcelink.json");
+            /// ```
+            source_link => windows_msvc_source_link
+        }
+        $crate::impl_msvc_absolute_macro! { $d
+            /// Prevents one or more default libraries from being used.
+            ///
+            /// This is unsafe so requires the `unsafe` keyword. See
+            /// [`LinkArgs::no_default_lib`](crate::windows::msvc::LinkArgs::no_default_lib).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// link_args::windows::no_default_lib!(unsafe "libcmt.lib", "libvcruntime.lib");
+            /// ```
+            no_default_lib => windows_msvc_no_default_lib
+        }
+        $crate::impl_msvc_absolute_macro! { $d stack_size_u64 => windows_msvc_stack_size_u64 }
+        $crate::impl_msvc_absolute_macro! { $d subsystem => windows_msvc_subsystem }
+        $crate::impl_msvc_absolute_macro! { $d export => windows_msvc_export }
+        $crate::impl_msvc_absolute_macro! { $d export_as => windows_msvc_export_as }
+        $crate::impl_msvc_absolute_macro! { $d align => windows_msvc_align }
+        $crate::impl_msvc_absolute_macro! { $d disable_all_default_libs => windows_msvc_disable_all_default_libs }
+        $crate::impl_msvc_absolute_macro! { $d merge => windows_msvc_merge }
+        $crate::impl_msvc_absolute_macro! { $d section => windows_msvc_section }
+        $crate::impl_msvc_absolute_macro! { $d base_with_file => windows_msvc_base_with_file }
+        $crate::impl_msvc_absolute_macro! { $d opt => windows_msvc_opt }
+        $crate::impl_msvc_absolute_macro! { $d map => windows_msvc_map }
+        $crate::impl_msvc_absolute_macro! { $d map_to => windows_msvc_map_to }
+        $crate::impl_msvc_absolute_macro! { $d guard => windows_msvc_guard }
+        $crate::impl_msvc_absolute_macro! { $d large_address_aware => windows_msvc_large_address_aware }
+        $crate::impl_msvc_absolute_macro! { $d delay_load => windows_msvc_delay_load }
+        $crate::impl_msvc_absolute_macro! { $d delay_load_with_helper => windows_msvc_delay_load_with_helper }
+        $crate::impl_msvc_absolute_macro! { $d whole_archive => windows_msvc_whole_archive }
+        $crate::impl_msvc_absolute_macro! { $d whole_archive_lib => windows_msvc_whole_archive_lib }
+        $crate::impl_msvc_absolute_macro! { $d function_pad_min => windows_msvc_function_pad_min }
+        $crate::impl_msvc_absolute_macro! { $d function_pad_min_bytes => windows_msvc_function_pad_min_bytes }
+        $crate::impl_msvc_absolute_macro! { $d machine => windows_msvc_machine }
+        $crate::impl_msvc_absolute_macro! { $d pdb => windows_msvc_pdb }
+        $crate::impl_msvc_absolute_macro! { $d pdb_alt_path => windows_msvc_pdb_alt_path }
+        $crate::impl_msvc_absolute_macro! { $d driver => windows_msvc_driver }
+        $crate::impl_msvc_absolute_macro! { $d swap_run => windows_msvc_swap_run }
+        $crate::impl_msvc_absolute_macro! { $d force => windows_msvc_force }
+        $crate::impl_msvc_absolute_macro! { $d force_all => windows_msvc_force_all }
+        $crate::impl_msvc_absolute_macro! { $d kernel => windows_msvc_kernel }
+        $crate::impl_msvc_absolute_macro! { $d ltcg => windows_msvc_ltcg }
+        $crate::impl_msvc_absolute_macro! { $d ltcg_incremental => windows_msvc_ltcg_incremental }
+        $crate::impl_msvc_absolute_macro! { $d ltcg_off => windows_msvc_ltcg_off }
+        $crate::impl_msvc_absolute_macro! { $d high_entropy_va => windows_msvc_high_entropy_va }
+        $crate::impl_msvc_absolute_macro! { $d app_container => windows_msvc_app_container }
+        $crate::impl_msvc_absolute_macro! { $d manifest_uac => windows_msvc_manifest_uac }
+        $crate::impl_msvc_absolute_macro! { $d manifest_input => windows_msvc_manifest_input }
+        $crate::impl_msvc_absolute_macro! { $d embed_manifest => windows_msvc_embed_manifest }
+        $crate::impl_msvc_absolute_macro! { $d verbose => windows_msvc_verbose }
+        $crate::impl_msvc_absolute_macro! { $d verbose_all => windows_msvc_verbose_all }
+        $crate::impl_msvc_absolute_macro! { $d file_align => windows_msvc_file_align }
+        $crate::impl_msvc_absolute_macro! { $d link_repro => windows_msvc_link_repro }
+        $crate::impl_msvc_absolute_macro! { $d emit_pogo_phase_info => windows_msvc_emit_pogo_phase_info }
+        $crate::impl_msvc_absolute_macro! { $d lib_path => windows_msvc_lib_path }
+        $crate::impl_msvc_absolute_macro! { $d dll => windows_msvc_dll }
+        $crate::impl_msvc_absolute_macro! { $d key_file => windows_msvc_key_file }
+        $crate::impl_msvc_absolute_macro! { $d key_container => windows_msvc_key_container }
+        $crate::impl_msvc_absolute_macro! { $d delay_sign => windows_msvc_delay_sign }
+        $crate::impl_msvc_absolute_macro! { $d tlb_out => windows_msvc_tlb_out }
+        $crate::impl_msvc_absolute_macro! { $d tlb_id => windows_msvc_tlb_id }
+        $crate::impl_msvc_absolute_macro! { $d winmd => windows_msvc_winmd }
+        $crate::impl_msvc_absolute_macro! { $d winmd_file => windows_msvc_winmd_file }
+        $crate::impl_msvc_absolute_macro! { $d pdb_stripped => windows_msvc_pdb_stripped }
+        $crate::impl_msvc_absolute_macro! { $d gen_profile => windows_msvc_gen_profile }
+        $crate::impl_msvc_absolute_macro! { $d use_profile => windows_msvc_use_profile }
+        $crate::impl_msvc_absolute_macro! { $d gen_profile_to => windows_msvc_gen_profile_to }
+        $crate::impl_msvc_absolute_macro! { $d use_profile_from => windows_msvc_use_profile_from }
+        $crate::impl_msvc_absolute_macro! { $d clr_image_type => windows_msvc_clr_image_type }
+        $crate::impl_msvc_absolute_macro! { $d clr_unmanaged_code_check => windows_msvc_clr_unmanaged_code_check }
+        $crate::impl_msvc_absolute_macro! { $d debug_type => windows_msvc_debug_type }
+        $crate::impl_msvc_absolute_macro! { $d default_libs => windows_msvc_default_libs }
+        $crate::impl_msvc_absolute_macro! { $d no_default_libs => windows_msvc_no_default_libs }
+        $crate::impl_msvc_absolute_macro! { $d default_libs_dedup => windows_msvc_default_libs_dedup }
+        $crate::impl_msvc_absolute_macro! { $d raw_bytes => windows_msvc_raw_bytes }
     };
 }
 
@@ -113,13 +662,110 @@ macro_rules! windows_msvc_default_lib {
 /// The following safe arguments can be set:
 ///
 ///  * [`stack_size`](crate::windows::msvc::LinkArgs::stack_size)
+///  * [`stack_size_u64`](crate::windows::msvc::LinkArgs::stack_size_u64)
+///  * [`heap_size`](crate::windows::msvc::LinkArgs::heap_size)
+///  * [`subsystem`](crate::windows::msvc::LinkArgs::subsystem)
+///  * [`entry`](crate::windows::msvc::LinkArgs::entry)
+///  * [`include`](crate::windows::msvc::LinkArgs::include)
+///  * [`export`](crate::windows::msvc::LinkArgs::export)
+///  * [`export_as`](crate::windows::msvc::LinkArgs::export_as)
+///  * [`align`](crate::windows::msvc::LinkArgs::align)
 ///  * [`default_lib`](crate::windows::msvc::LinkArgs::default_lib)
+///  * [`version`](crate::windows::msvc::LinkArgs::version)
+///  * [`opt`](crate::windows::msvc::LinkArgs::opt)
+///  * [`debug`](crate::windows::msvc::LinkArgs::debug)
+///  * [`map`](crate::windows::msvc::LinkArgs::map)
+///  * [`map_to`](crate::windows::msvc::LinkArgs::map_to)
+///  * [`guard`](crate::windows::msvc::LinkArgs::guard)
+///  * [`dynamic_base`](crate::windows::msvc::LinkArgs::dynamic_base) with `true`
+///  * [`nx_compat`](crate::windows::msvc::LinkArgs::nx_compat) with `true`
+///  * [`large_address_aware`](crate::windows::msvc::LinkArgs::large_address_aware)
+///  * [`ts_aware`](crate::windows::msvc::LinkArgs::ts_aware)
+///  * [`delay_load`](crate::windows::msvc::LinkArgs::delay_load)
+///  * [`delay_load_with_helper`](crate::windows::msvc::LinkArgs::delay_load_with_helper)
+///  * [`function_pad_min`](crate::windows::msvc::LinkArgs::function_pad_min)
+///  * [`function_pad_min_bytes`](crate::windows::msvc::LinkArgs::function_pad_min_bytes)
+///  * [`cet_compat`](crate::windows::msvc::LinkArgs::cet_compat) with `true`
+///  * [`release`](crate::windows::msvc::LinkArgs::release)
+///  * [`profile`](crate::windows::msvc::LinkArgs::profile)
+///  * [`safe_seh`](crate::windows::msvc::LinkArgs::safe_seh) with `true`
+///  * [`machine`](crate::windows::msvc::LinkArgs::machine)
+///  * [`machine_from_target`](crate::windows::msvc::LinkArgs::machine_from_target)
+///  * [`natvis`](crate::windows::msvc::LinkArgs::natvis)
+///  * [`impl_lib`](crate::windows::msvc::LinkArgs::impl_lib)
+///  * [`pdb`](crate::windows::msvc::LinkArgs::pdb)
+///  * [`pdb_alt_path`](crate::windows::msvc::LinkArgs::pdb_alt_path)
+///  * [`stub`](crate::windows::msvc::LinkArgs::stub)
+///  * [`swap_run`](crate::windows::msvc::LinkArgs::swap_run)
+///  * [`swap_run_all`](crate::windows::msvc::LinkArgs::swap_run_all)
+///  * [`fixed`](crate::windows::msvc::LinkArgs::fixed) with `false`
+///  * [`error_report`](crate::windows::msvc::LinkArgs::error_report)
+///  * [`integrity_check`](crate::windows::msvc::LinkArgs::integrity_check)
+///  * [`ignore_warning`](crate::windows::msvc::LinkArgs::ignore_warning)
+///  * [`ltcg`](crate::windows::msvc::LinkArgs::ltcg)
+///  * [`ltcg_incremental`](crate::windows::msvc::LinkArgs::ltcg_incremental)
+///  * [`ltcg_off`](crate::windows::msvc::LinkArgs::ltcg_off)
+///  * [`order`](crate::windows::msvc::LinkArgs::order)
+///  * [`high_entropy_va`](crate::windows::msvc::LinkArgs::high_entropy_va) with `true`
+///  * [`app_container`](crate::windows::msvc::LinkArgs::app_container)
+///  * [`manifest_uac`](crate::windows::msvc::LinkArgs::manifest_uac)
+///  * [`manifest`](crate::windows::msvc::LinkArgs::manifest)
+///  * [`manifest_input`](crate::windows::msvc::LinkArgs::manifest_input)
+///  * [`embed_manifest`](crate::windows::msvc::LinkArgs::embed_manifest)
+///  * [`verbose`](crate::windows::msvc::LinkArgs::verbose)
+///  * [`verbose_all`](crate::windows::msvc::LinkArgs::verbose_all)
+///  * [`no_logo`](crate::windows::msvc::LinkArgs::no_logo)
+///  * [`incremental`](crate::windows::msvc::LinkArgs::incremental)
+///  * [`file_align`](crate::windows::msvc::LinkArgs::file_align)
+///  * [`warnings_as_errors`](crate::windows::msvc::LinkArgs::warnings_as_errors)
+///  * [`link_repro`](crate::windows::msvc::LinkArgs::link_repro)
+///  * [`emit_pogo_phase_info`](crate::windows::msvc::LinkArgs::emit_pogo_phase_info)
+///  * [`lib_path`](crate::windows::msvc::LinkArgs::lib_path)
+///  * [`key_file`](crate::windows::msvc::LinkArgs::key_file)
+///  * [`key_container`](crate::windows::msvc::LinkArgs::key_container)
+///  * [`delay_sign`](crate::windows::msvc::LinkArgs::delay_sign)
+///  * [`tlb_out`](crate::windows::msvc::LinkArgs::tlb_out)
+///  * [`tlb_id`](crate::windows::msvc::LinkArgs::tlb_id)
+///  * [`winmd`](crate::windows::msvc::LinkArgs::winmd)
+///  * [`winmd_file`](crate::windows::msvc::LinkArgs::winmd_file)
+///  * [`source_link`](crate::windows::msvc::LinkArgs::source_link)
+///  * [`pdb_stripped`](crate::windows::msvc::LinkArgs::pdb_stripped)
+///  * [`gen_profile`](crate::windows::msvc::LinkArgs::gen_profile)
+///  * [`use_profile`](crate::windows::msvc::LinkArgs::use_profile)
+///  * [`gen_profile_to`](crate::windows::msvc::LinkArgs::gen_profile_to)
+///  * [`use_profile_from`](crate::windows::msvc::LinkArgs::use_profile_from)
+///  * [`clr_image_type`](crate::windows::msvc::LinkArgs::clr_image_type)
+///  * [`clr_unmanaged_code_check`](crate::windows::msvc::LinkArgs::clr_unmanaged_code_check)
+///  * [`debug_type`](crate::windows::msvc::LinkArgs::debug_type)
+///  * [`default_libs`](crate::windows::msvc::LinkArgs::default_libs)
+///  * [`default_libs_dedup`](crate::windows::msvc::LinkArgs::default_libs_dedup)
 ///
 /// The following unsafe arguments can be set:
 /// 
 ///  * [`no_default_lib`](crate::windows::msvc::LinkArgs::no_default_lib)
 ///  * [`disable_all_default_libs`](crate::windows::msvc::LinkArgs::disable_all_default_libs)
+///  * [`merge`](crate::windows::msvc::LinkArgs::merge)
+///  * [`section`](crate::windows::msvc::LinkArgs::section)
+///  * [`base`](crate::windows::msvc::LinkArgs::base)
+///  * [`base_with_file`](crate::windows::msvc::LinkArgs::base_with_file)
+///  * [`dynamic_base`](crate::windows::msvc::LinkArgs::dynamic_base) with `false`
+///  * [`nx_compat`](crate::windows::msvc::LinkArgs::nx_compat) with `false`
+///  * [`whole_archive`](crate::windows::msvc::LinkArgs::whole_archive)
+///  * [`whole_archive_lib`](crate::windows::msvc::LinkArgs::whole_archive_lib)
+///  * [`cet_compat`](crate::windows::msvc::LinkArgs::cet_compat) with `false`
+///  * [`dependent_load_flag`](crate::windows::msvc::LinkArgs::dependent_load_flag)
+///  * [`safe_seh`](crate::windows::msvc::LinkArgs::safe_seh) with `false`
+///  * [`driver`](crate::windows::msvc::LinkArgs::driver)
+///  * [`no_entry`](crate::windows::msvc::LinkArgs::no_entry)
+///  * [`fixed`](crate::windows::msvc::LinkArgs::fixed) with `true`
+///  * [`force`](crate::windows::msvc::LinkArgs::force)
+///  * [`force_all`](crate::windows::msvc::LinkArgs::force_all)
+///  * [`kernel`](crate::windows::msvc::LinkArgs::kernel)
+///  * [`high_entropy_va`](crate::windows::msvc::LinkArgs::high_entropy_va) with `false`
+///  * [`dll`](crate::windows::msvc::LinkArgs::dll)
+///  * [`no_default_libs`](crate::windows::msvc::LinkArgs::no_default_libs)
 ///  * [`raw`](crate::windows::msvc::LinkArgs::raw)
+///  * [`raw_bytes`](crate::windows::msvc::LinkArgs::raw_bytes)
 ///
 /// # Examples
 ///
@@ -128,6 +774,7 @@ macro_rules! windows_msvc_default_lib {
 /// ```rust
 /// link_args::windows! {
 ///     stack_size(0x80000);
+///     heap_size(0x100000);
 ///     default_lib("kernel32.lib", "Shell32.lib");
 /// }
 /// ```
@@ -147,74 +794,596 @@ macro_rules! windows_msvc_default_lib {
 ///     }
 /// }
 /// ```
+///
+/// # Empty blocks
+///
+/// An empty block sets no arguments. This can be useful when generating code.
+///
+/// ```rust
+/// link_args::windows! {}
+/// link_args::windows! {
+///     unsafe {}
+/// }
+/// ```
+///
+/// # Named statics
+///
+/// Starting the block with `static NAME;` creates a
+/// [`StaticDirective`](crate::windows::msvc::StaticDirective) with that name.
+/// The arguments written to the object file can then be checked using
+/// `NAME.bytes()`. The static only exists when targeting MSVC.
+///
+/// ```rust
+/// link_args::windows! {
+///     pub static LINK_ARGS;
+///     unsafe {
+///         no_entry();
+///     }
+/// }
+///
+/// #[cfg(all(windows, target_env = "msvc"))]
+/// assert_eq!(LINK_ARGS.bytes(), b"/NOENTRY ");
+/// ```
+///
+/// A named static with no arguments is empty.
+///
+/// ```rust
+/// link_args::windows! {
+///     static NO_ARGS;
+/// }
+/// link_args::windows! {
+///     static NO_UNSAFE_ARGS;
+///     unsafe {}
+/// }
+///
+/// #[cfg(all(windows, target_env = "msvc"))]
+/// assert_eq!(NO_ARGS.bytes(), b"");
+/// #[cfg(all(windows, target_env = "msvc"))]
+/// assert_eq!(NO_UNSAFE_ARGS.bytes(), b"");
+/// ```
+///
+/// # Conflicting arguments
+///
+/// Use `check_conflicts()` to fail compilation if a directive such as `STACK`
+/// is set more than once. It checks all the arguments before it in the same
+/// block. See [`LinkArgs::check_conflicts`](crate::windows::msvc::LinkArgs::check_conflicts).
+///
+/// ```rust
+/// link_args::windows! {
+///     stack_size(0x800000);
+///     entry("mainCRTStartup");
+///     check_conflicts();
+/// }
+/// ```
+///
+/// # Conditional arguments
+///
+/// Each argument can have `#[cfg]` attributes. Arguments that are `cfg`'d out
+/// are not set.
+///
+/// ```rust
+/// link_args::windows! {
+///     #[cfg(target_arch = "x86")]
+///     large_address_aware(true);
+///     #[cfg(target_pointer_width = "64")]
+///     high_entropy_va(true);
+/// }
+/// ```
 #[macro_export]
 macro_rules! windows {
+    ($vis:vis static $static:ident; $(unsafe {})?) => {
+        $crate::impl_msvc_bytes!(static $vis $static, 0, []);
+    };
+    ($vis:vis static $static:ident; unsafe {
+        $($(#[$meta:meta])* $name:ident($($args:tt)*));+;
+    }) => {
+        $crate::impl_msvc_bytes!(
+            static $vis $static,
+            $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+),
+            {
+                #[allow(unused_unsafe)]
+                let bytes = unsafe {
+                    $crate::impl_msvc_push!(
+                        $crate::windows::msvc::LinkArgs::new();
+                        $($(#[$meta])* $name($($args)*));+
+                    ).into_exact_array()
+                };
+                bytes
+            }
+        );
+    };
+    ($vis:vis static $static:ident; $($(#[$meta:meta])* $name:ident($($args:tt)*));+;) => {
+        $crate::impl_msvc_bytes!(
+            static $vis $static,
+            $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+),
+            $crate::impl_msvc_push!(
+                $crate::windows::msvc::LinkArgs::new();
+                $($(#[$meta])* $name($($args)*));+
+            ).into_exact_array()
+        );
+    };
+    () => {};
+    (unsafe {}) => {};
     (unsafe {
-        $($tt:tt(
-            $($expr:expr),*
-            $(,)?
-        ));+;
+        $($(#[$meta:meta])* $name:ident($($args:tt)*));+;
     }) => {
         #[cfg(target_env="msvc")]
         const _: () = {
-            use $crate::{impl_msvc_arg_size, impl_msvc_args, impl_msvc_bytes, windows::msvc::LinkArgs};
+            use $crate::{impl_msvc_bytes, windows::msvc::LinkArgs};
             enum ns {}
             impl ns {
-                const SIZE: usize = 0$(+ impl_msvc_arg_size!($tt($($expr),*)))+;
+                const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
                 #[allow(unused_unsafe)]
                 const BUFFER: LinkArgs::<{ns::SIZE}> = unsafe {
-                    let mut buf = LinkArgs::new();
-                    $(
-                        buf = impl_msvc_args!(buf, $tt($($expr),*));
-                    )+
-                    buf
+                    $crate::impl_msvc_push!(LinkArgs::new(); $($(#[$meta])* $name($($args)*));+)
                 };
             }
-            impl_msvc_bytes!(ns::SIZE, ns::BUFFER.into_array());
+            impl_msvc_bytes!(ns::SIZE, ns::BUFFER.into_exact_array());
         };
     };
-    ($($tt:tt(
-        $($expr:expr),*
-        $(,)?
-    ));+;) => {
+    ($($(#[$meta:meta])* $name:ident($($args:tt)*));+;) => {
         #[cfg(target_env="msvc")]
         const _: () = {
-            use $crate::{impl_msvc_arg_size, impl_msvc_args, impl_msvc_bytes, windows::msvc::LinkArgs};
+            use $crate::{impl_msvc_bytes, windows::msvc::LinkArgs};
             enum ns {}
             impl ns {
-                const SIZE: usize = 0$(+ impl_msvc_arg_size!($tt($($expr),*)))+;
-                const BUFFER: LinkArgs::<{ns::SIZE}> = {
-                    let mut buf = LinkArgs::new();
-                    $(
-                        buf = impl_msvc_args!(buf, $tt($($expr),*));
-                    )+
-                    buf
-                };
+                const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+                const BUFFER: LinkArgs::<{ns::SIZE}> = $crate::impl_msvc_push!(LinkArgs::new(); $($(#[$meta])* $name($($args)*));+);
+            }
+            impl_msvc_bytes!(ns::SIZE, ns::BUFFER.into_exact_array());
+        };
+    };
+}
+
+/// Adds up the size of each argument, skipping any that are `cfg`'d out.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_size {
+    ($($(#[$meta:meta])* $name:ident($($args:tt)*));+) => {{
+        let size = 0;
+        $(
+            $(#[$meta])*
+            let size = size + $crate::impl_msvc_arg_size!($name($($args)*));
+        )+
+        size
+    }};
+}
+
+/// Pushes each argument onto `$buf`, skipping any that are `cfg`'d out.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_push {
+    ($buf:expr; $($(#[$meta:meta])* $name:ident($($args:tt)*));+) => {{
+        let buf = $buf;
+        $(
+            $(#[$meta])*
+            let buf = $crate::impl_msvc_args!(buf, $name($($args)*));
+        )+
+        buf
+    }};
+}
+
+/// The same as [`windows!`].
+#[macro_export]
+macro_rules! windows_msvc {
+    ($($args:tt)*) => {
+        $crate::windows! { $($args)* }
+    };
+}
+
+/// Combines multiple groups of arguments into a single linker directive.
+///
+/// Each [`windows!`] block creates its own static. This macro takes any number
+/// of blocks, in the same form as `windows!`, and puts all of their arguments
+/// into one static in the order they are given. Blocks that use unsafe
+/// arguments must be marked `unsafe`.
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows_args! {
+///     {
+///         stack_size(0x800000);
+///         default_lib("kernel32.lib");
+///     }
+///     unsafe {
+///         no_default_lib("libcmt.lib");
+///     }
+///     {
+///         subsystem(Console);
+///     }
+/// }
+/// ```
+///
+/// Because all the arguments end up in one place, `check_conflicts()` in the
+/// last block checks the arguments from every block.
+///
+/// ```rust
+/// link_args::windows_args! {
+///     {
+///         stack_size(0x800000);
+///     }
+///     unsafe {
+///         raw("/ENTRY:mainCRTStartup");
+///     }
+///     {
+///         check_conflicts();
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! windows_args {
+    ($($groups:tt)*) => {
+        #[cfg(target_env="msvc")]
+        const _: () = {
+            use $crate::{impl_msvc_bytes, windows::msvc::LinkArgs};
+            enum ns {}
+            impl ns {
+                const SIZE: usize = $crate::impl_msvc_groups_size!(0; $($groups)*);
+                const BUFFER: LinkArgs::<{ns::SIZE}> = $crate::impl_msvc_groups!(LinkArgs::new(); $($groups)*);
+            }
+            impl_msvc_bytes!(ns::SIZE, ns::BUFFER.into_exact_array());
+        };
+    };
+}
+
+/// Adds up the size of each group of arguments given to `windows_args!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_groups_size {
+    ($size:expr;) => {
+        $size
+    };
+    ($size:expr; $(unsafe)? {} $($rest:tt)*) => {
+        $crate::impl_msvc_groups_size!($size; $($rest)*)
+    };
+    ($size:expr; unsafe { $($(#[$meta:meta])* $name:ident($($args:tt)*));+; } $($rest:tt)*) => {
+        $crate::impl_msvc_groups_size!(
+            $size + $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+            $($rest)*
+        )
+    };
+    ($size:expr; { $($(#[$meta:meta])* $name:ident($($args:tt)*));+; } $($rest:tt)*) => {
+        $crate::impl_msvc_groups_size!(
+            $size + $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+            $($rest)*
+        )
+    };
+}
+
+/// Pushes each group of arguments given to `windows_args!`, in order.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_groups {
+    ($buf:expr;) => {
+        $buf
+    };
+    ($buf:expr; $(unsafe)? {} $($rest:tt)*) => {
+        $crate::impl_msvc_groups!($buf; $($rest)*)
+    };
+    ($buf:expr; unsafe { $($(#[$meta:meta])* $name:ident($($args:tt)*));+; } $($rest:tt)*) => {
+        $crate::impl_msvc_groups!(
+            {
+                let buf = $buf;
+                #[allow(unused_unsafe)]
+                unsafe { $crate::impl_msvc_push!(buf; $($(#[$meta])* $name($($args)*));+) }
+            };
+            $($rest)*
+        )
+    };
+    ($buf:expr; { $($(#[$meta:meta])* $name:ident($($args:tt)*));+; } $($rest:tt)*) => {
+        $crate::impl_msvc_groups!(
+            $crate::impl_msvc_push!($buf; $($(#[$meta])* $name($($args)*));+);
+            $($rest)*
+        )
+    };
+}
+
+/// Returns the arguments that [`windows!`] would set as a `String`.
+///
+/// This takes the same arguments as `windows!` but only builds the string. It
+/// can be used on any target and does not set any linker arguments. It's only
+/// meant to help with debugging, see [`preview`](crate::windows::msvc::preview).
+///
+/// # Examples
+///
+/// ```rust
+/// let args = link_args::windows_preview! {
+///     stack_size(0x800000);
+///     default_lib("kernel32.lib");
+/// };
+/// assert_eq!(args, "/STACK:0x800000 /DEFAULTLIB:\"kernel32.lib\" ");
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! windows_preview {
+    ($(unsafe {})?) => {
+        $crate::windows::msvc::preview(&$crate::windows::msvc::LinkArgs::<0>::new())
+    };
+    (unsafe {
+        $($(#[$meta:meta])* $name:ident($($args:tt)*));+;
+    }) => {{
+        use $crate::windows::msvc::LinkArgs;
+        const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+        #[allow(unused_unsafe)]
+        const BUFFER: LinkArgs::<SIZE> = unsafe {
+            $crate::impl_msvc_push!(LinkArgs::new(); $($(#[$meta])* $name($($args)*));+)
+        };
+        $crate::windows::msvc::preview(&BUFFER)
+    }};
+    ($($(#[$meta:meta])* $name:ident($($args:tt)*));+;) => {{
+        use $crate::windows::msvc::LinkArgs;
+        const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+        const BUFFER: LinkArgs::<SIZE> = $crate::impl_msvc_push!(LinkArgs::new(); $($(#[$meta])* $name($($args)*));+);
+        $crate::windows::msvc::preview(&BUFFER)
+    }};
+}
+
+/// The same as [`windows!`] but can also show the generated arguments.
+///
+/// When the `dump` feature is enabled this fails to compile for Windows MSVC
+/// targets, with an error message containing the arguments. Otherwise it's
+/// exactly the same as `windows!`. Like the arguments themselves, the error is
+/// never emitted for other targets so enabling `dump` doesn't break them.
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "dump", windows, target_env = "msvc"), doc = "```compile_fail")]
+#[cfg_attr(not(all(feature = "dump", windows, target_env = "msvc")), doc = "```rust")]
+/// link_args::windows::dump! {
+///     stack_size(0x800000);
+///     default_lib("kernel32.lib");
+/// }
+/// ```
+///
+/// With the `dump` feature enabled, this gives an error such as:
+///
+/// ```text
+/// error[E0080]: evaluation panicked: /STACK:0x800000 /DEFAULTLIB:"kernel32.lib"
+/// ```
+///
+/// A named static can be dumped too.
+///
+#[cfg_attr(all(feature = "dump", windows, target_env = "msvc"), doc = "```compile_fail")]
+#[cfg_attr(not(all(feature = "dump", windows, target_env = "msvc")), doc = "```rust")]
+/// link_args::windows::dump! {
+///     static LINK_ARGS;
+///     stack_size(0x800000);
+/// }
+/// ```
+#[macro_export]
+macro_rules! windows_dump {
+    ($($args:tt)*) => {
+        $crate::windows! { $($args)* }
+        $crate::impl_msvc_dump! { $($args)* }
+    };
+}
+
+#[cfg(not(feature = "dump"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_dump {
+    ($($args:tt)*) => {};
+}
+
+/// Panics during const evaluation with the arguments as the message, for MSVC
+/// targets only.
+#[cfg(feature = "dump")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_dump {
+    ($vis:vis static $static:ident; $($args:tt)*) => {
+        $crate::impl_msvc_dump! { $($args)* }
+    };
+    ($(unsafe {})?) => {
+        #[cfg(all(windows, target_env = "msvc"))]
+        const _: () = panic!("link_args: no arguments");
+    };
+    (unsafe {
+        $($(#[$meta:meta])* $name:ident($($args:tt)*));+;
+    }) => {
+        #[cfg(all(windows, target_env = "msvc"))]
+        const _: () = {
+            use $crate::windows::msvc::LinkArgs;
+            const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+            #[allow(unused_unsafe)]
+            const BYTES: [u8; SIZE] = unsafe {
+                $crate::impl_msvc_push!(LinkArgs::<SIZE>::new(); $($(#[$meta])* $name($($args)*));+).into_exact_array()
+            };
+            if !$crate::windows::msvc::is_utf8(&BYTES) {
+                panic!("link_args: the arguments can't be shown because they are not valid UTF-8");
+            }
+            // SAFETY: The arguments were checked to be valid UTF-8 above.
+            panic!("{}", unsafe { ::core::str::from_utf8_unchecked(&BYTES) });
+        };
+    };
+    ($($(#[$meta:meta])* $name:ident($($args:tt)*));+;) => {
+        #[cfg(all(windows, target_env = "msvc"))]
+        const _: () = {
+            use $crate::windows::msvc::LinkArgs;
+            const SIZE: usize = $crate::impl_msvc_size!($($(#[$meta])* $name($($args)*));+);
+            const BYTES: [u8; SIZE] = $crate::impl_msvc_push!(LinkArgs::<SIZE>::new(); $($(#[$meta])* $name($($args)*));+)
+                .into_exact_array();
+            if !$crate::windows::msvc::is_utf8(&BYTES) {
+                panic!("link_args: the arguments can't be shown because they are not valid UTF-8");
             }
-            impl_msvc_bytes!(ns::SIZE, ns::BUFFER.into_array());
+            // SAFETY: The arguments were checked to be valid UTF-8 above.
+            panic!("{}", unsafe { ::core::str::from_utf8_unchecked(&BYTES) });
         };
     };
 }
 
+/// The same as [`windows!`] except that it fails to compile for targets that
+/// can't use the linker arguments.
+///
+/// By default the linker arguments are silently ignored for non-MSVC targets,
+/// which keeps crates portable. Use this instead if the arguments are required
+/// for the program to work correctly, e.g. a large stack size.
+///
+/// # Examples
+///
+/// This fails to compile for any target other than Windows MSVC.
+///
+#[cfg_attr(all(windows, target_env = "msvc"), doc = "```rust")]
+#[cfg_attr(not(all(windows, target_env = "msvc")), doc = "```compile_fail")]
+/// link_args::windows_strict! {
+///     stack_size(0x800000);
+/// }
+/// ```
+///
+/// The error names the target, for example:
+///
+/// ```text
+/// error: link_args: the target with target_os = "linux" and target_env = "gnu" can't use these linker arguments, use a windows-msvc target
+/// ```
+#[macro_export]
+macro_rules! windows_strict {
+    ($($args:tt)*) => {
+        $crate::impl_msvc_strict_check!();
+        $crate::windows! { $($args)* }
+    };
+}
+
+/// Fails to compile if the target can't use MSVC linker arguments.
+///
+/// `cfg` can't be used to get the name of the target so there is an error for
+/// each known `target_os` and `target_env` pair, which includes their values.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_msvc_strict_check {
+    () => {
+        $crate::impl_msvc_strict_check! {
+            target_os [
+                "windows" "linux" "macos" "ios" "android" "freebsd" "netbsd" "openbsd"
+                "dragonfly" "illumos" "solaris" "fuchsia" "redox" "haiku" "wasi"
+                "emscripten" "uefi" "none"
+            ]
+            target_env ["" "gnu" "msvc" "musl" "sgx" "uclibc" "newlib"]
+        }
+    };
+    (target_os [$($os:literal)*] target_env [$($env:literal)*]) => {
+        #[cfg(not(any($(target_os = $os),*)))]
+        compile_error!("link_args: this target can't use these linker arguments, use a windows-msvc target");
+        $crate::impl_msvc_strict_check! { @os [$($os)*] [$($env)*] }
+    };
+    (@os [$os:literal $($rest:literal)*] [$($env:literal)*]) => {
+        $(
+            #[cfg(all(target_os = $os, target_env = $env, not(all(windows, target_env = "msvc"))))]
+            compile_error!(concat!(
+                "link_args: the target with target_os = \"", $os, "\" and target_env = \"", $env,
+                "\" can't use these linker arguments, use a windows-msvc target"
+            ));
+        )*
+        #[cfg(all(target_os = $os, not(any($(target_env = $env),*))))]
+        compile_error!(concat!(
+            "link_args: the target with target_os = \"", $os,
+            "\" can't use these linker arguments, use a windows-msvc target"
+        ));
+        $crate::impl_msvc_strict_check! { @os [$($rest)*] [$($env)*] }
+    };
+    (@os [] [$($env:literal)*]) => {};
+}
+
 /// Build the linker arguments using a macro.
+///
+/// Some arguments are only safe with one value, such as `dynamic_base(true)`.
+/// Any other value needs `unsafe`:
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); dynamic_base(false));
+/// ```
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); nx_compat(false));
+/// ```
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); cet_compat(false));
+/// ```
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); safe_seh(false));
+/// ```
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); fixed(true));
+/// ```
+///
+/// ```compile_fail
+/// use link_args::windows::msvc::LinkArgs;
+///
+/// const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); high_entropy_va(false));
+/// ```
 #[doc(hidden)]
 #[macro_export]
 macro_rules! impl_msvc_args {
     // These are (probably) safe.
-    ($args:expr, stack_size($reserve:expr)) => {
+    ($args:expr, stack_size($reserve:expr $(,)?)) => {
         $args.stack_size($reserve)
     };
-    ($args:expr, stack_size($reserve:expr, $commit:expr)) => {
+    ($args:expr, stack_size($reserve:expr, $commit:expr $(,)?)) => {
         $args.stack_size_with_commit($reserve, $commit)
     };
-    ($args:expr, default_lib($($lib:expr),+)) => {
+    ($args:expr, stack_size_u64($reserve:expr $(,)?)) => {
+        $args.stack_size_u64($reserve)
+    };
+    ($args:expr, stack_size_u64($reserve:expr, $commit:expr $(,)?)) => {
+        $args.stack_size_with_commit_u64($reserve, $commit)
+    };
+    ($args:expr, heap_size($reserve:expr $(,)?)) => {
+        $args.heap_size($reserve)
+    };
+    ($args:expr, heap_size($reserve:expr, $commit:expr $(,)?)) => {
+        $args.heap_size_with_commit($reserve, $commit)
+    };
+    ($args:expr, subsystem($subsystem:ident $(,)?)) => {
+        $args.subsystem($crate::windows::msvc::Subsystem::$subsystem)
+    };
+    ($args:expr, subsystem($subsystem:ident, $major:expr, $minor:expr $(,)?)) => {
+        $args.subsystem_with_version($crate::windows::msvc::Subsystem::$subsystem, $major, $minor)
+    };
+    ($args:expr, entry($symbol:expr $(,)?)) => {
+        $args.entry($symbol)
+    };
+    ($args:expr, include($($symbol:expr),+ $(,)?)) => {
+        $args
+        $(
+            .include($symbol)
+        )+
+    };
+    ($args:expr, export($name:expr $(,)?)) => {
+        $args.export($name, $crate::windows::msvc::ExportOptions::new())
+    };
+    ($args:expr, export($name:expr, $options:expr $(,)?)) => {
+        $args.export($name, $options)
+    };
+    ($args:expr, export_as($external:expr, $internal:expr $(,)?)) => {
+        $args.export_as($external, $internal, $crate::windows::msvc::ExportOptions::new())
+    };
+    ($args:expr, export_as($external:expr, $internal:expr, $options:expr $(,)?)) => {
+        $args.export_as($external, $internal, $options)
+    };
+    ($args:expr, align($bytes:expr $(,)?)) => {
+        $args.align($bytes)
+    };
+    ($args:expr, default_lib($($lib:expr),+ $(,)?)) => {
         $args
         $(
             .default_lib($lib)
         )+
     };
     // These are unsafe
-    ($args:expr, no_default_lib($($lib:expr),+)) => {
+    ($args:expr, no_default_lib($($lib:expr),+ $(,)?)) => {
         $args
         $(
             .no_default_lib($lib)
@@ -223,8 +1392,310 @@ macro_rules! impl_msvc_args {
     ($args:expr, disable_all_default_libs()) => {
         $args.disable_all_default_libs()
     };
-    ($args:expr, raw($raw:expr)) => {
-        $args.raw($raw)
+    ($args:expr, merge($from:expr, $to:expr $(,)?)) => {
+        $args.merge($from, $to)
+    };
+    ($args:expr, section($name:expr, $flags:expr $(,)?)) => {
+        $args.section($name, $flags)
+    };
+    ($args:expr, base($address:expr $(,)?)) => {
+        $args.base($address)
+    };
+    ($args:expr, base_with_file($filename:expr, $key:expr $(,)?)) => {
+        $args.base_with_file($filename, $key)
+    };
+    ($args:expr, version($major:expr, $minor:expr $(,)?)) => {
+        $args.version($major, $minor)
+    };
+    ($args:expr, opt($flags:expr $(,)?)) => {
+        $args.opt($flags)
+    };
+    ($args:expr, debug($mode:ident $(,)?)) => {
+        $args.debug($crate::windows::msvc::DebugMode::$mode)
+    };
+    ($args:expr, map()) => {
+        $args.map()
+    };
+    ($args:expr, map_to($filename:expr $(,)?)) => {
+        $args.map_to($filename)
+    };
+    ($args:expr, guard($($flags:ident),+ $(,)?)) => {
+        $args
+        $(
+            .guard($crate::windows::msvc::GuardFlags::$flags)
+        )+
+    };
+    ($args:expr, dynamic_base(true $(,)?)) => {{
+        let args = $args;
+        // Enabling ASLR is always safe.
+        unsafe { args.dynamic_base(true) }
+    }};
+    ($args:expr, dynamic_base($enabled:expr $(,)?)) => {
+        $args.dynamic_base($enabled)
+    };
+    ($args:expr, nx_compat(true $(,)?)) => {{
+        let args = $args;
+        // Enabling DEP is always safe.
+        unsafe { args.nx_compat(true) }
+    }};
+    ($args:expr, nx_compat($enabled:expr $(,)?)) => {
+        $args.nx_compat($enabled)
+    };
+    ($args:expr, large_address_aware($enabled:expr $(,)?)) => {
+        $args.large_address_aware($enabled)
+    };
+    ($args:expr, ts_aware($enabled:expr $(,)?)) => {
+        $args.ts_aware($enabled)
+    };
+    ($args:expr, delay_load($($dll:expr),+ $(,)?)) => {
+        $args
+        $(
+            .delay_load($dll)
+        )+
+    };
+    ($args:expr, delay_load_with_helper($($dll:expr),+ $(,)?)) => {
+        $args.delay_load_with_helper(&[$($dll),+])
+    };
+    ($args:expr, whole_archive()) => {
+        $args.whole_archive()
+    };
+    ($args:expr, whole_archive_lib($($lib:expr),+ $(,)?)) => {
+        $args
+        $(
+            .whole_archive_lib($lib)
+        )+
+    };
+    ($args:expr, function_pad_min()) => {
+        $args.function_pad_min()
+    };
+    ($args:expr, function_pad_min_bytes($bytes:expr $(,)?)) => {
+        $args.function_pad_min_bytes($bytes)
+    };
+    ($args:expr, cet_compat(true $(,)?)) => {{
+        let args = $args;
+        // Enabling CET is always safe.
+        unsafe { args.cet_compat(true) }
+    }};
+    ($args:expr, cet_compat($enabled:expr $(,)?)) => {
+        $args.cet_compat($enabled)
+    };
+    ($args:expr, dependent_load_flag($flags:expr $(,)?)) => {
+        $args.dependent_load_flag($flags)
+    };
+    ($args:expr, release()) => {
+        $args.release()
+    };
+    ($args:expr, profile()) => {
+        $args.profile()
+    };
+    ($args:expr, safe_seh(true $(,)?)) => {{
+        let args = $args;
+        // Enabling safe exception handlers is always safe.
+        unsafe { args.safe_seh(true) }
+    }};
+    ($args:expr, safe_seh($enabled:expr $(,)?)) => {
+        $args.safe_seh($enabled)
+    };
+    ($args:expr, machine($machine:ident $(,)?)) => {
+        $args.machine($crate::windows::msvc::Machine::$machine)
+    };
+    ($args:expr, machine_from_target()) => {
+        $args.machine_from_target()
+    };
+    ($args:expr, natvis($path:expr $(,)?)) => {
+        $args.natvis($path)
+    };
+    ($args:expr, impl_lib($path:expr $(,)?)) => {
+        $args.impl_lib($path)
+    };
+    ($args:expr, pdb($path:expr $(,)?)) => {
+        $args.pdb($path)
+    };
+    ($args:expr, pdb_alt_path($path:expr $(,)?)) => {
+        $args.pdb_alt_path($path)
+    };
+    ($args:expr, stub($path:expr $(,)?)) => {
+        $args.stub($path)
+    };
+    ($args:expr, driver($mode:ident $(,)?)) => {
+        $args.driver($crate::windows::msvc::DriverMode::$mode)
+    };
+    ($args:expr, no_entry()) => {
+        $args.no_entry()
+    };
+    ($args:expr, swap_run($swap_run:ident $(,)?)) => {
+        $args.swap_run($crate::windows::msvc::SwapRun::$swap_run)
+    };
+    ($args:expr, swap_run($($swap_run:ident),+ $(,)?)) => {
+        $args.swap_run_all(&[$($crate::windows::msvc::SwapRun::$swap_run),+])
+    };
+    ($args:expr, fixed(false $(,)?)) => {{
+        let args = $args;
+        // Keeping the relocation table is always safe.
+        unsafe { args.fixed(false) }
+    }};
+    ($args:expr, fixed($enabled:expr $(,)?)) => {
+        $args.fixed($enabled)
+    };
+    ($args:expr, error_report($error_report:ident $(,)?)) => {
+        $args.error_report($crate::windows::msvc::ErrorReport::$error_report)
+    };
+    ($args:expr, force($force_mode:ident $(,)?)) => {
+        $args.force($crate::windows::msvc::ForceMode::$force_mode)
+    };
+    ($args:expr, force_all()) => {
+        $args.force_all()
+    };
+    ($args:expr, integrity_check()) => {
+        $args.integrity_check()
+    };
+    ($args:expr, kernel()) => {
+        $args.kernel()
+    };
+    ($args:expr, ignore_warning($($number:expr),+ $(,)?)) => {
+        $args
+        $(
+            .ignore_warning($number)
+        )+
+    };
+    ($args:expr, ltcg()) => {
+        $args.ltcg()
+    };
+    ($args:expr, ltcg_incremental()) => {
+        $args.ltcg_incremental()
+    };
+    ($args:expr, ltcg_off()) => {
+        $args.ltcg_off()
+    };
+    ($args:expr, order($response_file:expr $(,)?)) => {
+        $args.order($response_file)
+    };
+    ($args:expr, high_entropy_va(true $(,)?)) => {{
+        let args = $args;
+        // Enabling high entropy ASLR is always safe.
+        unsafe { args.high_entropy_va(true) }
+    }};
+    ($args:expr, high_entropy_va($enabled:expr $(,)?)) => {
+        $args.high_entropy_va($enabled)
+    };
+    ($args:expr, app_container($enabled:expr $(,)?)) => {
+        $args.app_container($enabled)
+    };
+    ($args:expr, manifest_uac($level:expr, $ui_access:expr $(,)?)) => {
+        $args.manifest_uac($level, $ui_access)
+    };
+    ($args:expr, manifest($manifest_mode:ident $(,)?)) => {
+        $args.manifest($crate::windows::msvc::ManifestMode::$manifest_mode)
+    };
+    ($args:expr, manifest_input($path:expr $(,)?)) => {
+        $args.manifest_input($path)
+    };
+    ($args:expr, embed_manifest($path:expr $(,)?)) => {
+        $args.embed_manifest($path)
+    };
+    ($args:expr, verbose($verbose:ident $(,)?)) => {
+        $args.verbose($crate::windows::msvc::Verbose::$verbose)
+    };
+    ($args:expr, verbose_all()) => {
+        $args.verbose_all()
+    };
+    ($args:expr, no_logo()) => {
+        $args.no_logo()
+    };
+    ($args:expr, incremental($enabled:expr $(,)?)) => {
+        $args.incremental($enabled)
+    };
+    ($args:expr, file_align($bytes:expr $(,)?)) => {
+        $args.file_align($bytes)
+    };
+    ($args:expr, warnings_as_errors($enabled:expr $(,)?)) => {
+        $args.warnings_as_errors($enabled)
+    };
+    ($args:expr, link_repro($dir:expr $(,)?)) => {
+        $args.link_repro($dir)
+    };
+    ($args:expr, emit_pogo_phase_info()) => {
+        $args.emit_pogo_phase_info()
+    };
+    ($args:expr, lib_path($($path:expr),+ $(,)?)) => {
+        $args
+        $(
+            .lib_path($path)
+        )+
+    };
+    ($args:expr, dll()) => {
+        $args.dll()
+    };
+    ($args:expr, key_file($path:expr $(,)?)) => {
+        $args.key_file($path)
+    };
+    ($args:expr, key_container($name:expr $(,)?)) => {
+        $args.key_container($name)
+    };
+    ($args:expr, delay_sign($enabled:expr $(,)?)) => {
+        $args.delay_sign($enabled)
+    };
+    ($args:expr, tlb_out($path:expr $(,)?)) => {
+        $args.tlb_out($path)
+    };
+    ($args:expr, tlb_id($id:expr $(,)?)) => {
+        $args.tlb_id($id)
+    };
+    ($args:expr, winmd($enabled:expr $(,)?)) => {
+        $args.winmd($enabled)
+    };
+    ($args:expr, winmd_file($path:expr $(,)?)) => {
+        $args.winmd_file($path)
+    };
+    ($args:expr, source_link($path:expr $(,)?)) => {
+        $args.source_link($path)
+    };
+    ($args:expr, pdb_stripped($path:expr $(,)?)) => {
+        $args.pdb_stripped($path)
+    };
+    ($args:expr, gen_profile()) => {
+        $args.gen_profile()
+    };
+    ($args:expr, use_profile()) => {
+        $args.use_profile()
+    };
+    ($args:expr, gen_profile_to($pgd:expr $(,)?)) => {
+        $args.gen_profile_to($pgd)
+    };
+    ($args:expr, use_profile_from($pgd:expr $(,)?)) => {
+        $args.use_profile_from($pgd)
+    };
+    ($args:expr, clr_image_type($clr_image_type:ident $(,)?)) => {
+        $args.clr_image_type($crate::windows::msvc::ClrImageType::$clr_image_type)
+    };
+    ($args:expr, clr_unmanaged_code_check($enabled:expr $(,)?)) => {
+        $args.clr_unmanaged_code_check($enabled)
+    };
+    ($args:expr, debug_type($($types:ident),+ $(,)?)) => {
+        $args.debug_type(
+            $crate::windows::msvc::DebugType::empty()
+            $(
+                .union($crate::windows::msvc::DebugType::$types)
+            )+
+        )
+    };
+    ($args:expr, default_libs($libs:expr $(,)?)) => {
+        $args.default_libs($libs)
+    };
+    ($args:expr, no_default_libs($libs:expr $(,)?)) => {
+        $args.no_default_libs($libs)
+    };
+    ($args:expr, default_libs_dedup($libs:expr $(,)?)) => {
+        $args.default_libs_dedup($libs)
+    };
+    ($args:expr, check_conflicts()) => {
+        $args.check_conflicts()
+    };
+    ($args:expr, raw($($raw:expr),+ $(,)?)) => {
+        $args$(.raw($raw))+
+    };
+    ($args:expr, raw_bytes($($raw:expr),+ $(,)?)) => {
+        $args$(.raw_bytes($raw))+
     };
 }
 
@@ -233,19 +1704,64 @@ macro_rules! impl_msvc_args {
 #[macro_export]
 macro_rules! impl_msvc_arg_size {
     // These are (probably) safe.
-    (stack_size($reserve:expr)) => {
-        $crate::windows::msvc::ArgSize::STACK_SIZE
+    (stack_size($reserve:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::stack_size($reserve)
+    };
+    (stack_size($reserve:expr, $commit:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::stack_size_with_commit($reserve, $commit)
+    };
+    (stack_size_u64($reserve:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::stack_size_u64($reserve)
+    };
+    (stack_size_u64($reserve:expr, $commit:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::stack_size_with_commit_u64($reserve, $commit)
     };
-    (stack_size($reserve:expr, $commit:expr)) => {
-        $crate::windows::msvc::ArgSize::STACK_SIZE_WITH_COMMIT
+    (heap_size($reserve:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::heap_size($reserve)
     };
-    (default_lib($($lib:expr),+)) => {
+    (heap_size($reserve:expr, $commit:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::heap_size_with_commit($reserve, $commit)
+    };
+    (subsystem($subsystem:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::subsystem($crate::windows::msvc::Subsystem::$subsystem)
+    };
+    (subsystem($subsystem:ident, $major:expr, $minor:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::subsystem_with_version(
+            $crate::windows::msvc::Subsystem::$subsystem, $major, $minor
+        )
+    };
+    (entry($symbol:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::entry($symbol)
+    };
+    (include($($symbol:expr),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::include($symbol)
+        )+
+    };
+    (export($name:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::export($name, $crate::windows::msvc::ExportOptions::new())
+    };
+    (export($name:expr, $options:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::export($name, $options)
+    };
+    (export_as($external:expr, $internal:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::export_as(
+            $external, $internal, $crate::windows::msvc::ExportOptions::new()
+        )
+    };
+    (export_as($external:expr, $internal:expr, $options:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::export_as($external, $internal, $options)
+    };
+    (align($bytes:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::align($bytes)
+    };
+    (default_lib($($lib:expr),+ $(,)?)) => {
         0$(
             +$crate::windows::msvc::ArgSize::default_lib($lib)
         )+
     };
     // These are unsafe.
-    (no_default_lib($($lib:expr),+)) => {
+    (no_default_lib($($lib:expr),+ $(,)?)) => {
         0$(
             +$crate::windows::msvc::ArgSize::no_default_lib($lib)
         )+
@@ -253,7 +1769,274 @@ macro_rules! impl_msvc_arg_size {
     (disable_all_default_libs()) => {
         $crate::windows::msvc::ArgSize::DISABLE_ALL_DEFAULT_LIBS
     };
-    (raw($lib:expr)) => {
-        $lib.len() + 1
+    (merge($from:expr, $to:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::merge($from, $to)
+    };
+    (section($name:expr, $flags:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::section($name, $flags)
+    };
+    (base($address:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::base($address)
+    };
+    (base_with_file($filename:expr, $key:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::base_with_file($filename, $key)
+    };
+    (version($major:expr, $minor:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::version($major, $minor)
+    };
+    (opt($flags:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::opt($flags)
+    };
+    (debug($mode:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::debug($crate::windows::msvc::DebugMode::$mode)
+    };
+    (map()) => {
+        $crate::windows::msvc::ArgSize::MAP
+    };
+    (map_to($filename:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::map_to($filename)
+    };
+    (guard($($flags:ident),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::guard($crate::windows::msvc::GuardFlags::$flags)
+        )+
+    };
+    (dynamic_base($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::dynamic_base($enabled)
+    };
+    (nx_compat($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::nx_compat($enabled)
+    };
+    (large_address_aware($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::large_address_aware($enabled)
+    };
+    (ts_aware($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::ts_aware($enabled)
+    };
+    (delay_load($($dll:expr),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::delay_load($dll)
+        )+
+    };
+    (delay_load_with_helper($($dll:expr),+ $(,)?)) => {
+        $crate::windows::msvc::ArgSize::delay_load_with_helper(&[$($dll),+])
+    };
+    (whole_archive()) => {
+        $crate::windows::msvc::ArgSize::WHOLE_ARCHIVE
+    };
+    (whole_archive_lib($($lib:expr),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::whole_archive_lib($lib)
+        )+
+    };
+    (function_pad_min()) => {
+        $crate::windows::msvc::ArgSize::FUNCTION_PAD_MIN
+    };
+    (function_pad_min_bytes($bytes:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::function_pad_min_bytes($bytes)
+    };
+    (cet_compat($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::cet_compat($enabled)
+    };
+    (dependent_load_flag($flags:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::dependent_load_flag($flags)
+    };
+    (release()) => {
+        $crate::windows::msvc::ArgSize::RELEASE
+    };
+    (profile()) => {
+        $crate::windows::msvc::ArgSize::PROFILE
+    };
+    (safe_seh($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::safe_seh($enabled)
+    };
+    (machine($machine:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::machine($crate::windows::msvc::Machine::$machine)
+    };
+    (machine_from_target()) => {
+        $crate::windows::msvc::ArgSize::machine_from_target()
+    };
+    (natvis($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::natvis($path)
+    };
+    (impl_lib($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::impl_lib($path)
+    };
+    (pdb($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::pdb($path)
+    };
+    (pdb_alt_path($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::pdb_alt_path($path)
+    };
+    (stub($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::stub($path)
+    };
+    (driver($mode:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::driver($crate::windows::msvc::DriverMode::$mode)
+    };
+    (no_entry()) => {
+        $crate::windows::msvc::ArgSize::NO_ENTRY
+    };
+    (swap_run($swap_run:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::swap_run($crate::windows::msvc::SwapRun::$swap_run)
+    };
+    (swap_run($($swap_run:ident),+ $(,)?)) => {
+        $crate::windows::msvc::ArgSize::swap_run_all(&[$($crate::windows::msvc::SwapRun::$swap_run),+])
+    };
+    (fixed($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::fixed($enabled)
+    };
+    (error_report($error_report:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::error_report($crate::windows::msvc::ErrorReport::$error_report)
+    };
+    (force($force_mode:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::force($crate::windows::msvc::ForceMode::$force_mode)
+    };
+    (force_all()) => {
+        $crate::windows::msvc::ArgSize::FORCE_ALL
+    };
+    (integrity_check()) => {
+        $crate::windows::msvc::ArgSize::INTEGRITY_CHECK
+    };
+    (kernel()) => {
+        $crate::windows::msvc::ArgSize::KERNEL
+    };
+    (ignore_warning($($number:expr),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::ignore_warning($number)
+        )+
+    };
+    (ltcg()) => {
+        $crate::windows::msvc::ArgSize::LTCG
+    };
+    (ltcg_incremental()) => {
+        $crate::windows::msvc::ArgSize::LTCG_INCREMENTAL
+    };
+    (ltcg_off()) => {
+        $crate::windows::msvc::ArgSize::LTCG_OFF
+    };
+    (order($response_file:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::order($response_file)
+    };
+    (high_entropy_va($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::high_entropy_va($enabled)
+    };
+    (app_container($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::app_container($enabled)
+    };
+    (manifest_uac($level:expr, $ui_access:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::manifest_uac($level, $ui_access)
+    };
+    (manifest($manifest_mode:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::manifest($crate::windows::msvc::ManifestMode::$manifest_mode)
+    };
+    (manifest_input($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::manifest_input($path)
+    };
+    (embed_manifest($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::embed_manifest($path)
+    };
+    (verbose($verbose:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::verbose($crate::windows::msvc::Verbose::$verbose)
+    };
+    (verbose_all()) => {
+        $crate::windows::msvc::ArgSize::VERBOSE_ALL
+    };
+    (no_logo()) => {
+        $crate::windows::msvc::ArgSize::NO_LOGO
+    };
+    (incremental($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::incremental($enabled)
+    };
+    (file_align($bytes:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::file_align($bytes)
+    };
+    (warnings_as_errors($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::warnings_as_errors($enabled)
+    };
+    (link_repro($dir:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::link_repro($dir)
+    };
+    (emit_pogo_phase_info()) => {
+        $crate::windows::msvc::ArgSize::EMIT_POGO_PHASE_INFO
+    };
+    (lib_path($($path:expr),+ $(,)?)) => {
+        0$(
+            +$crate::windows::msvc::ArgSize::lib_path($path)
+        )+
+    };
+    (dll()) => {
+        $crate::windows::msvc::ArgSize::DLL
+    };
+    (key_file($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::key_file($path)
+    };
+    (key_container($name:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::key_container($name)
+    };
+    (delay_sign($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::delay_sign($enabled)
+    };
+    (tlb_out($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::tlb_out($path)
+    };
+    (tlb_id($id:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::tlb_id($id)
+    };
+    (winmd($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::winmd($enabled)
+    };
+    (winmd_file($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::winmd_file($path)
+    };
+    (source_link($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::source_link($path)
+    };
+    (pdb_stripped($path:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::pdb_stripped($path)
+    };
+    (gen_profile()) => {
+        $crate::windows::msvc::ArgSize::GEN_PROFILE
+    };
+    (use_profile()) => {
+        $crate::windows::msvc::ArgSize::USE_PROFILE
+    };
+    (gen_profile_to($pgd:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::gen_profile_to($pgd)
+    };
+    (use_profile_from($pgd:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::use_profile_from($pgd)
+    };
+    (clr_image_type($clr_image_type:ident $(,)?)) => {
+        $crate::windows::msvc::ArgSize::clr_image_type($crate::windows::msvc::ClrImageType::$clr_image_type)
+    };
+    (clr_unmanaged_code_check($enabled:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::clr_unmanaged_code_check($enabled)
+    };
+    (debug_type($($types:ident),+ $(,)?)) => {
+        $crate::windows::msvc::ArgSize::debug_type(
+            $crate::windows::msvc::DebugType::empty()
+            $(
+                .union($crate::windows::msvc::DebugType::$types)
+            )+
+        )
+    };
+    (default_libs($libs:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::default_libs($libs)
+    };
+    (no_default_libs($libs:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::no_default_libs($libs)
+    };
+    (default_libs_dedup($libs:expr $(,)?)) => {
+        $crate::windows::msvc::ArgSize::default_libs_dedup($libs)
+    };
+    (check_conflicts()) => {
+        0
+    };
+    (raw($($raw:expr),+ $(,)?)) => {
+        0 $(+ $raw.len() + 1)+
+    };
+    (raw_bytes($($raw:expr),+ $(,)?)) => {
+        0 $(+ $raw.len() + 1)+
     };
 }
@@ -0,0 +1,83 @@
+//! Helpers for writing sizes in bytes.
+//!
+//! These expand to a plain integer expression so the result has whatever
+//! integer type the surrounding code expects, e.g. `u32` for
+//! [`LinkArgs::stack_size`](crate::windows::msvc::LinkArgs::stack_size) or
+//! `u64` for
+//! [`LinkArgs::stack_size_u64`](crate::windows::msvc::LinkArgs::stack_size_u64).
+//! Linker arguments are always evaluated in a const context so a size that
+//! overflows its type is a compile error.
+
+/// The number of bytes in a number of kibibytes (1024 bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows! {
+///     heap_size(link_args::kib!(512));
+/// }
+/// assert_eq!(link_args::kib!(512), 0x80000);
+/// ```
+///
+/// Sizes that don't fit in the integer type fail to compile.
+///
+/// ```compile_fail
+/// const SIZE: u32 = link_args::kib!(4 * 1024 * 1024);
+/// ```
+#[macro_export]
+macro_rules! kib {
+    ($n:expr) => {
+        ($n) * 0x400
+    };
+}
+
+/// The number of bytes in a number of mebibytes (1024 kibibytes).
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows! {
+///     stack_size(link_args::mib!(8));
+/// }
+/// link_args::windows::stack_size!(link_args::mib!(8), link_args::mib!(4));
+/// assert_eq!(link_args::mib!(8), 0x800000);
+/// ```
+///
+/// Sizes that don't fit in the integer type fail to compile.
+///
+/// ```compile_fail
+/// const SIZE: u32 = link_args::mib!(4096);
+/// ```
+#[macro_export]
+macro_rules! mib {
+    ($n:expr) => {
+        ($n) * 0x10_0000
+    };
+}
+
+/// The number of bytes in a number of gibibytes (1024 mebibytes).
+///
+/// # Examples
+///
+/// ```rust
+/// link_args::windows! {
+///     stack_size(link_args::gib!(1));
+/// }
+/// ```
+///
+/// Use [`LinkArgs::stack_size_u64`](crate::windows::msvc::LinkArgs::stack_size_u64)
+/// for sizes of 4 GiB or larger.
+///
+/// ```rust
+/// link_args::windows::stack_size_u64!(link_args::gib!(8));
+/// ```
+///
+/// ```compile_fail
+/// const SIZE: u32 = link_args::gib!(4);
+/// ```
+#[macro_export]
+macro_rules! gib {
+    ($n:expr) => {
+        ($n) * 0x4000_0000
+    };
+}
@@ -0,0 +1,19 @@
+use link_args::windows::msvc::{ArgSize, LinkArgs};
+
+#[test]
+#[should_panic(expected = "link_args: buffer capacity 1 exceeded by directive")]
+fn push_past_capacity() {
+    LinkArgs::<1>::new().no_logo();
+}
+
+#[test]
+#[should_panic(expected = "link_args: buffer capacity 15 exceeded by directive")]
+fn push_past_capacity_with_earlier_arguments() {
+    LinkArgs::<{ ArgSize::NO_LOGO + 7 }>::new().no_logo().no_logo();
+}
+
+#[test]
+fn push_to_capacity() {
+    let args = LinkArgs::<{ ArgSize::NO_LOGO }>::new().no_logo();
+    assert_eq!(args.len(), args.capacity());
+}
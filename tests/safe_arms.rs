@@ -0,0 +1,47 @@
+//! The `windows!` macro only emits its static for MSVC targets, so these use
+//! the macro's argument parsing directly to check it on any host.
+use link_args::windows::msvc::LinkArgs;
+
+#[test]
+fn enabled_mitigations_are_safe() {
+    const ARGS: LinkArgs<128> = link_args::impl_msvc_push!(
+        LinkArgs::new();
+        dynamic_base(true);
+        nx_compat(true);
+        cet_compat(true);
+        fixed(false);
+        high_entropy_va(true)
+    );
+    assert_eq!(
+        ARGS.as_str(),
+        "/DYNAMICBASE /NXCOMPAT /CETCOMPAT /FIXED:NO /HIGHENTROPYVA ",
+    );
+}
+
+#[test]
+fn disabled_mitigations_need_unsafe() {
+    const ARGS: LinkArgs<128> = unsafe {
+        link_args::impl_msvc_push!(
+            LinkArgs::new();
+            dynamic_base(false);
+            nx_compat(false);
+            cet_compat(false);
+            fixed(true);
+            high_entropy_va(false)
+        )
+    };
+    assert_eq!(
+        ARGS.as_str(),
+        "/DYNAMICBASE:NO /NXCOMPAT:NO /CETCOMPAT:NO /FIXED /HIGHENTROPYVA:NO ",
+    );
+}
+
+#[test]
+fn safe_seh_is_x86_only() {
+    const ARGS: LinkArgs<64> = link_args::impl_msvc_push!(LinkArgs::new(); safe_seh(true));
+    if cfg!(target_arch = "x86") {
+        assert_eq!(ARGS.as_str(), "/SAFESEH ");
+    } else {
+        assert!(ARGS.is_empty());
+    }
+}